@@ -0,0 +1,42 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+#[allow(dead_code)]
+#[path = "src/services_file.rs"]
+mod services_file;
+
+/// Service database embedded in the binary
+const SERVICES_FILE: &str = "data/nmap-services";
+
+/// Generate the embedded service table from `SERVICES_FILE`
+fn main() {
+    println!("cargo:rerun-if-changed={}", SERVICES_FILE);
+    println!("cargo:rerun-if-changed=src/services_file.rs");
+
+    let input = fs::read_to_string(SERVICES_FILE).expect("Failed to read services file");
+    let services = services_file::parse(&input)
+        .unwrap_or_else(|e| panic!("Invalid services file {}: {}", SERVICES_FILE, e));
+    let order = services_file::frequency_order(&services);
+
+    let mut code = String::new();
+    code.push_str("/// Embedded services sorted by protocol & port number\n");
+    code.push_str("#[allow(clippy::approx_constant)]\n");
+    code.push_str("static SERVICES: &[Service] = &[\n");
+    for service in services.iter() {
+        writeln!(
+            code,
+            "    Service {{ name: Cow::Borrowed({:?}), number: {}, protocol: Protocol::{:?}, frequency: {:?} }},",
+            service.name, service.number, service.protocol, service.frequency
+        )
+        .unwrap();
+    }
+    code.push_str("];\n\n");
+    code.push_str("/// Indices into `SERVICES` by descending open-frequency\n");
+    writeln!(code, "static FREQUENCY_ORDER: &[u32] = &{:?};", order).unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR not set");
+    fs::write(Path::new(&out_dir).join("services.rs"), code)
+        .expect("Failed to write service table");
+}
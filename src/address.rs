@@ -0,0 +1,243 @@
+use std::ffi::{CStr, CString};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV6};
+use std::str::FromStr;
+
+/// Largest network, in addresses, accepted in CIDR notation
+const MAX_NETWORK_SIZE: u128 = 1 << 16;
+
+/// A target given on the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TargetSpec {
+    /// Host name to resolve
+    Host(String),
+    /// Literal address, including the scope id of link-local ipv6 addresses
+    Address(SocketAddr),
+    /// Network in CIDR notation
+    Network { address: SocketAddr, prefix: u8 },
+}
+
+impl FromStr for TargetSpec {
+    type Err = String;
+
+    /// Parse a host name, an ipv4 address, a bracketed or unbracketed ipv6 address
+    /// with optional `%zone` or any of those addresses with a `/prefix`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains('/') {
+            let (address, prefix) = parse_network(s)?;
+            let bits: u8 = if address.is_ipv4() { 32 } else { 128 };
+            if 1u128 << (bits - prefix).min(127) > MAX_NETWORK_SIZE {
+                return Err(format!(
+                    "network /{} is larger than {} addresses",
+                    prefix, MAX_NETWORK_SIZE
+                ));
+            }
+            return Ok(TargetSpec::Network { address, prefix });
+        }
+
+        let unbracketed = s.strip_prefix('[').and_then(|s| s.strip_suffix(']'));
+        if unbracketed.is_some() || s.contains('%') || s.parse::<IpAddr>().is_ok() {
+            return parse_address(s).map(TargetSpec::Address);
+        }
+        Ok(TargetSpec::Host(s.to_string()))
+    }
+}
+
+impl TargetSpec {
+    /// Addresses of a literal address or network, `None` for host names
+    pub fn addresses(&self) -> Option<Vec<SocketAddr>> {
+        match self {
+            TargetSpec::Host(_) => None,
+            TargetSpec::Address(address) => Some(vec![*address]),
+            TargetSpec::Network { address, prefix } => Some(network_addresses(address, *prefix)),
+        }
+    }
+
+    /// Amount of addresses, host names count as one
+    pub fn size(&self) -> u128 {
+        match self {
+            TargetSpec::Host(_) | TargetSpec::Address(_) => 1,
+            TargetSpec::Network { address, prefix } => {
+                let bits: u8 = if address.is_ipv4() { 32 } else { 128 };
+                1u128 << (bits - prefix).min(127)
+            }
+        }
+    }
+}
+
+/// Parse a network in CIDR notation, of any size
+pub fn parse_network(s: &str) -> Result<(SocketAddr, u8), String> {
+    let (address, prefix) = s
+        .rsplit_once('/')
+        .ok_or_else(|| format!("missing prefix length in '{}'", s))?;
+    let address = parse_address(address)?;
+    let prefix: u8 = prefix
+        .parse()
+        .map_err(|_| format!("invalid prefix length '{}'", prefix))?;
+    let bits: u8 = if address.is_ipv4() { 32 } else { 128 };
+    if prefix > bits {
+        return Err(format!("prefix length {} exceeds {} bits", prefix, bits));
+    }
+    Ok((address, prefix))
+}
+
+/// Check if `address` is in the network of `network` & `prefix`
+pub fn network_contains(network: &SocketAddr, prefix: u8, address: &SocketAddr) -> bool {
+    match (network.ip(), address.ip()) {
+        (IpAddr::V4(network), IpAddr::V4(ip)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            u32::from(network) & mask == u32::from(ip) & mask
+        }
+        (IpAddr::V6(network), IpAddr::V6(ip)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            u128::from(network) & mask == u128::from(ip) & mask
+        }
+        _ => false,
+    }
+}
+
+/// All addresses of a network, the scope id of `address` is kept
+fn network_addresses(address: &SocketAddr, prefix: u8) -> Vec<SocketAddr> {
+    match address {
+        SocketAddr::V4(address) => {
+            let host_bits = 32 - prefix as u32;
+            let mask = u32::MAX.checked_shl(host_bits).unwrap_or(0);
+            let first = u32::from(*address.ip()) & mask;
+            (0..1u64 << host_bits)
+                .map(|offset| SocketAddr::new(Ipv4Addr::from(first + offset as u32).into(), 0))
+                .collect()
+        }
+        SocketAddr::V6(address) => {
+            let host_bits = 128 - prefix as u32;
+            let mask = u128::MAX.checked_shl(host_bits).unwrap_or(0);
+            let first = u128::from(*address.ip()) & mask;
+            (0..1u128 << host_bits)
+                .map(|offset| {
+                    let ip = Ipv6Addr::from(first + offset);
+                    SocketAddrV6::new(ip, 0, 0, address.scope_id()).into()
+                })
+                .collect()
+        }
+    }
+}
+
+/// Parse an address, with optional brackets & `%zone` for ipv6
+fn parse_address(s: &str) -> Result<SocketAddr, String> {
+    let s = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s);
+    let (ip, zone) = match s.split_once('%') {
+        Some((ip, zone)) => (ip, Some(zone)),
+        None => (s, None),
+    };
+    let ip: IpAddr = ip
+        .parse()
+        .map_err(|_| format!("invalid ip address '{}'", ip))?;
+
+    match (ip, zone) {
+        (ip, None) => Ok(SocketAddr::new(ip, 0)),
+        (IpAddr::V6(ip), Some(zone)) => {
+            let scope_id = scope_id(zone).ok_or(format!("unknown zone '{}'", zone))?;
+            Ok(SocketAddrV6::new(ip, 0, 0, scope_id).into())
+        }
+        (IpAddr::V4(_), Some(_)) => Err(format!("zone on ipv4 address '{}'", s)),
+    }
+}
+
+/// Scope id of a numeric zone or interface name
+fn scope_id(zone: &str) -> Option<u32> {
+    if let Ok(id) = zone.parse() {
+        return Some(id);
+    }
+    let name = CString::new(zone).ok()?;
+    // Safety: `name` is a nul terminated string
+    match unsafe { libc::if_nametoindex(name.as_ptr()) } {
+        0 => None,
+        index => Some(index),
+    }
+}
+
+/// Interface name of a scope id
+fn zone_name(scope_id: u32) -> Option<String> {
+    let mut name = [0 as libc::c_char; libc::IF_NAMESIZE];
+    // Safety: `name` is a buffer of IF_NAMESIZE bytes, as if_indextoname requires
+    let res = unsafe { libc::if_indextoname(scope_id, name.as_mut_ptr()) };
+    if res.is_null() {
+        return None;
+    }
+    // Safety: if_indextoname succeeded, so `name` holds a nul terminated string
+    let name = unsafe { CStr::from_ptr(name.as_ptr()) };
+    name.to_str().ok().map(str::to_string)
+}
+
+/// Format the ip of an address, including the zone of scoped ipv6 addresses
+pub fn display_ip(address: &SocketAddr) -> String {
+    match address {
+        SocketAddr::V6(address) if address.scope_id() != 0 => {
+            let zone =
+                zone_name(address.scope_id()).unwrap_or_else(|| address.scope_id().to_string());
+            format!("{}%{}", address.ip(), zone)
+        }
+        address => address.ip().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check host names, bracketed & unbracketed literals
+    #[test]
+    fn parse_literals() {
+        let host: TargetSpec = "example.com".parse().unwrap();
+        assert_eq!(host, TargetSpec::Host("example.com".to_string()));
+
+        let expected = TargetSpec::Address("[::1]:0".parse().unwrap());
+        assert_eq!("::1".parse::<TargetSpec>().unwrap(), expected);
+        assert_eq!("[::1]".parse::<TargetSpec>().unwrap(), expected);
+
+        let v4: TargetSpec = "192.0.2.1".parse().unwrap();
+        assert_eq!(v4, TargetSpec::Address("192.0.2.1:0".parse().unwrap()));
+    }
+
+    /// Check numeric & named zones of link-local addresses
+    #[test]
+    fn parse_zones() {
+        let numeric: TargetSpec = "fe80::1%1".parse().unwrap();
+        let named: TargetSpec = "[fe80::1%lo]".parse().unwrap();
+        let expected = SocketAddrV6::new("fe80::1".parse().unwrap(), 0, 0, 1);
+
+        assert_eq!(numeric, TargetSpec::Address(expected.into()));
+        assert_eq!(named, TargetSpec::Address(expected.into()));
+        assert_eq!(display_ip(&expected.into()), "fe80::1%lo");
+        assert!("fe80::1%nosuchinterface0".parse::<TargetSpec>().is_err());
+        assert!("192.0.2.1%1".parse::<TargetSpec>().is_err());
+    }
+
+    /// Check network expansion & size limits
+    #[test]
+    fn parse_networks() {
+        let v6: TargetSpec = "[2001:db8::17]/126".parse().unwrap();
+        let addresses = v6.addresses().unwrap();
+        assert_eq!(addresses.len(), 4);
+        assert_eq!(addresses[0], "[2001:db8::14]:0".parse().unwrap());
+        assert_eq!(addresses[3], "[2001:db8::17]:0".parse().unwrap());
+
+        let v4: TargetSpec = "192.0.2.9/30".parse().unwrap();
+        let addresses = v4.addresses().unwrap();
+        assert_eq!(addresses.first(), Some(&"192.0.2.8:0".parse().unwrap()));
+        assert_eq!(addresses.last(), Some(&"192.0.2.11:0".parse().unwrap()));
+
+        assert!("2001:db8::/64".parse::<TargetSpec>().is_err());
+        assert!("192.0.2.0/33".parse::<TargetSpec>().is_err());
+        assert_eq!(
+            "10.0.0.0/16"
+                .parse::<TargetSpec>()
+                .unwrap()
+                .addresses()
+                .unwrap()
+                .len(),
+            1 << 16
+        );
+    }
+}
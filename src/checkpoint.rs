@@ -0,0 +1,333 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio::time::{interval, Duration, MissedTickBehavior};
+
+use crate::common_ports::{Protocol, ServiceRegistry};
+use crate::error::Error;
+use crate::port::{Port, PortState, PortStates, ProbeResult, Target};
+
+/// Time between checkpoint writes while scanning
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Progress of a scan, saved to resume it after an interruption
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Port lists of the targets, usually one shared by all
+    pub ports: Vec<Vec<u16>>,
+    pub targets: Vec<CheckpointTarget>,
+}
+
+/// The port states of a target, unknown ports are not scanned yet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointTarget {
+    pub address: SocketAddr,
+    pub names: Vec<String>,
+    /// Index of the target's port list in [`Checkpoint::ports`]
+    pub ports: usize,
+    /// States of the ports, packed by [`PortStates::to_hex`]
+    pub states: String,
+}
+
+impl Checkpoint {
+    /// Checkpoint of the probed ports of `targets`
+    pub fn new(targets: &[Target]) -> Checkpoint {
+        let mut checkpoint = Checkpoint::default();
+        let mut lists: HashMap<*const Port, usize> = HashMap::new();
+        for target in targets.iter() {
+            let ports = *lists
+                .entry(Arc::as_ptr(&target.ports) as *const Port)
+                .or_insert_with(|| {
+                    let numbers = target.ports.iter().map(|port| port.number).collect();
+                    checkpoint.ports.push(numbers);
+                    checkpoint.ports.len() - 1
+                });
+            checkpoint.targets.push(CheckpointTarget {
+                address: target.address,
+                names: target.names.clone(),
+                ports,
+                states: target.states.to_hex(),
+            });
+        }
+        checkpoint
+    }
+
+    /// Load a checkpoint file
+    pub fn load(path: &Path) -> Result<Checkpoint, Error> {
+        let error = |source| Error::Checkpoint {
+            path: path.to_owned(),
+            source,
+        };
+        let content = fs::read(path).map_err(error)?;
+        let checkpoint: Checkpoint =
+            serde_json::from_slice(&content).map_err(|e| error(e.into()))?;
+        for target in checkpoint.targets.iter() {
+            if checkpoint.states(target).is_none() {
+                let reason = format!("invalid port states of {}", target.address.ip());
+                return Err(error(io::Error::new(io::ErrorKind::InvalidData, reason)));
+            }
+        }
+        Ok(checkpoint)
+    }
+
+    /// Save to a checkpoint file, replacing it at once so it is never half written
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let error = |source| Error::Checkpoint {
+            path: path.to_owned(),
+            source,
+        };
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        let content = serde_json::to_vec(self).map_err(|e| error(e.into()))?;
+        fs::write(&temporary, content).map_err(error)?;
+        fs::rename(&temporary, path).map_err(error)
+    }
+
+    /// Port list & states of a target, `None` if they do not match
+    fn states<'a>(&'a self, target: &CheckpointTarget) -> Option<(&'a [u16], PortStates)> {
+        let ports = self.ports.get(target.ports)?;
+        Some((ports, PortStates::from_hex(&target.states, ports.len())?))
+    }
+
+    /// Restore the states of probed ports into `targets`
+    ///
+    /// Checkpointed targets missing from `targets` are added with their probed
+    /// ports, so their results are kept.
+    pub fn restore(&self, targets: &mut Vec<Target>, registry: &ServiceRegistry) {
+        let mut indices: HashMap<SocketAddr, usize> = targets
+            .iter()
+            .enumerate()
+            .map(|(index, target)| (target.address, index))
+            .collect();
+
+        for saved in self.targets.iter() {
+            let Some((numbers, states)) = self.states(saved) else {
+                continue;
+            };
+            let probed = || {
+                numbers
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| states.get(*index) != PortState::Unknown)
+                    .map(|(index, &number)| (number, states.get(index)))
+            };
+            let index = *indices.entry(saved.address).or_insert_with(|| {
+                let ports: Arc<[Port]> = probed()
+                    .map(|(number, _)| Port {
+                        service: registry
+                            .service_name(number, Protocol::Tcp)
+                            .unwrap_or("unknown"),
+                        number,
+                    })
+                    .collect();
+                targets.push(Target {
+                    names: Vec::new(),
+                    hostname: None,
+                    address: saved.address,
+                    states: PortStates::new(ports.len()),
+                    ports,
+                    shard: None,
+                });
+                targets.len() - 1
+            });
+
+            let target = &mut targets[index];
+            for name in saved.names.iter() {
+                if !target.names.contains(name) {
+                    target.names.push(name.clone());
+                }
+            }
+            // Usually the port list is unchanged, otherwise ports are matched by number
+            if target
+                .ports
+                .iter()
+                .map(|port| port.number)
+                .eq(numbers.iter().copied())
+            {
+                target.states = states;
+                continue;
+            }
+            let positions: HashMap<u16, usize> = target
+                .ports
+                .iter()
+                .enumerate()
+                .map(|(index, port)| (port.number, index))
+                .collect();
+            for (number, state) in probed() {
+                if let Some(&index) = positions.get(&number) {
+                    target.states.set(index, state);
+                }
+            }
+        }
+    }
+}
+
+/// Save `checkpoint` to `path` periodically while recording the probe results
+/// of its targets, and a last time when all senders of `probes` are dropped
+///
+/// Failed saves are reported & retried, only the result of the last one is returned.
+pub fn spawn_checkpointer(
+    path: PathBuf,
+    mut checkpoint: Checkpoint,
+    mut probes: mpsc::UnboundedReceiver<ProbeResult>,
+) -> JoinHandle<Result<(), Error>> {
+    tokio::spawn(async move {
+        let indices: HashMap<SocketAddr, usize> = checkpoint
+            .targets
+            .iter()
+            .enumerate()
+            .map(|(index, target)| (target.address, index))
+            .collect();
+        let positions: Vec<HashMap<u16, usize>> = checkpoint
+            .ports
+            .iter()
+            .map(|ports| {
+                let positions = ports.iter().enumerate();
+                positions.map(|(index, &number)| (number, index)).collect()
+            })
+            .collect();
+        let mut states: Vec<PortStates> = checkpoint
+            .targets
+            .iter()
+            .map(|target| checkpoint.states(target).expect("Checkpoint is valid").1)
+            .collect();
+        let mut ticks = interval(CHECKPOINT_INTERVAL);
+        ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        let mut changed = HashSet::new();
+        let mut unsaved = false;
+
+        loop {
+            tokio::select! {
+                probe = probes.recv() => match probe {
+                    Some(ProbeResult { address, state }) => {
+                        let mut host = address;
+                        host.set_port(0);
+                        let Some(&index) = indices.get(&host) else {
+                            continue;
+                        };
+                        let ports = &positions[checkpoint.targets[index].ports];
+                        if let Some(&position) = ports.get(&address.port()) {
+                            states[index].set(position, state);
+                            changed.insert(index);
+                        }
+                    }
+                    None => break,
+                },
+                _ = ticks.tick() => {
+                    // Only the states of changed targets are packed again
+                    for index in changed.drain() {
+                        checkpoint.targets[index].states = states[index].to_hex();
+                        unsaved = true;
+                    }
+                    if unsaved {
+                        match checkpoint.save(&path) {
+                            Ok(()) => unsaved = false,
+                            // Tried again on the next tick
+                            Err(error) => eprintln!("Saving checkpoint failed: {}", error),
+                        }
+                    }
+                }
+            }
+        }
+        for index in changed {
+            checkpoint.targets[index].states = states[index].to_hex();
+        }
+        checkpoint.save(&path)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that probed ports are restored, also into changed port lists, & missing targets added
+    #[test]
+    fn restore_into_targets() {
+        let ssh = Port {
+            service: "ssh",
+            number: 22,
+        };
+        let http = Port {
+            service: "http",
+            number: 80,
+        };
+        let address = "192.0.2.1:0".parse().unwrap();
+        let reordered = "192.0.2.3:0".parse().unwrap();
+        let mut targets = vec![
+            Target::new("example.com".to_string(), address, Arc::new([ssh, http])),
+            Target::new("192.0.2.3".to_string(), reordered, Arc::new([http, ssh])),
+        ];
+        let saved = |address, name: &str, ports, states: &str| CheckpointTarget {
+            address,
+            names: vec![name.to_string()],
+            ports,
+            states: states.to_string(),
+        };
+        let checkpoint = Checkpoint {
+            ports: vec![vec![22, 80], vec![443]],
+            targets: vec![
+                // 22 open
+                saved(address, "www.example.com", 0, "01"),
+                // 22 open & 80 closed
+                saved(reordered, "192.0.2.3", 0, "09"),
+                // 443 closed
+                saved("192.0.2.2:0".parse().unwrap(), "192.0.2.2", 1, "02"),
+            ],
+        };
+
+        checkpoint.restore(&mut targets, &ServiceRegistry::embedded());
+        assert_eq!(targets[0].names, vec!["example.com", "www.example.com"]);
+        assert_eq!(targets[0].states.get(0), PortState::Open);
+        assert_eq!(targets[0].unscanned(), 1);
+        assert_eq!(targets[1].states.get(0), PortState::Closed);
+        assert_eq!(targets[1].states.get(1), PortState::Open);
+        assert_eq!(targets[2].ports[0].service, "https");
+        assert_eq!(targets[2].states.get(0), PortState::Closed);
+
+        let restored = Checkpoint::new(&targets);
+        assert_eq!(restored.ports, vec![vec![22, 80], vec![80, 22], vec![443]]);
+        assert_eq!(restored.targets[2].states, "02");
+    }
+
+    /// Check that probe results are saved when the scan ends
+    #[tokio::test]
+    async fn checkpointer_saves_results() {
+        let path = std::env::temp_dir().join(format!("checkpoint-{}.json", std::process::id()));
+        let ports: Arc<[Port]> = Arc::new([Port {
+            service: "ssh",
+            number: 22,
+        }]);
+        let address = "192.0.2.1:0".parse().unwrap();
+        let targets = vec![Target::new("192.0.2.1".to_string(), address, ports)];
+
+        let (probes_tx, probes_rx) = mpsc::unbounded_channel();
+        let checkpointer = spawn_checkpointer(path.clone(), Checkpoint::new(&targets), probes_rx);
+        probes_tx
+            .send(ProbeResult {
+                address: "192.0.2.1:22".parse().unwrap(),
+                state: PortState::Filtered,
+            })
+            .unwrap();
+        drop(probes_tx);
+        checkpointer.await.unwrap().unwrap();
+
+        let mut checkpoint = Checkpoint::load(&path).unwrap();
+        let (_, states) = checkpoint.states(&checkpoint.targets[0]).unwrap();
+        assert_eq!(states.get(0), PortState::Filtered);
+
+        checkpoint.targets[0].states = "0".to_string();
+        checkpoint.save(&path).unwrap();
+        assert!(matches!(
+            Checkpoint::load(&path),
+            Err(Error::Checkpoint { .. })
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+}
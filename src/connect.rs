@@ -0,0 +1,215 @@
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use std::sync::Arc;
+
+use tokio::net::{TcpSocket, TcpStream};
+use tokio::sync::OnceCell;
+
+use crate::error::Error;
+use crate::port::PortState;
+use crate::proxy::{probe_through, Proxy};
+use crate::ssh::SshJump;
+
+/// Opens the tcp connections of probes, optionally from:
+/// - A source address
+/// - A fixed source port, shared by concurrent probes (SO_REUSEADDR & SO_REUSEPORT)
+/// - A network interface (SO_BINDTODEVICE)
+///
+/// Probes are tunnelled through `proxies` when given, the first proxy is
+/// connected to from the configured source. With `ssh` they are opened as
+/// channels of the jump host's connection instead.
+#[derive(Debug, Clone, Default)]
+pub struct Connector {
+    pub source_ip: Option<IpAddr>,
+    pub source_port: Option<u16>,
+    pub interface: Option<String>,
+    pub proxies: Vec<Proxy>,
+    pub ssh: Option<Arc<SshJump>>,
+    /// Address of the first proxy, resolved by the first probe through it
+    pub first_proxy: OnceCell<SocketAddr>,
+}
+
+impl Connector {
+    /// Check that sockets can be bound as configured
+    pub fn check(&self) -> Result<(), Error> {
+        let local = SocketAddr::new(self.source_ip.unwrap_or(Ipv4Addr::UNSPECIFIED.into()), 0);
+        self.socket(local)
+            .map(|_| ())
+            .map_err(|source| Error::Bind {
+                local: self.describe(),
+                source,
+            })
+    }
+
+    /// Probe a port of `target`
+    ///
+    /// A refused connection means closed, unreachable networks & hosts are
+    /// reported as `Unroutable`.
+    pub async fn probe(&self, target: SocketAddr) -> Result<PortState, Error> {
+        if let Some(ssh) = &self.ssh {
+            return ssh.probe(target).await;
+        }
+        if !self.proxies.is_empty() {
+            return probe_through(&self.proxies, self, target).await;
+        }
+
+        match self.connect(target).await {
+            Ok(_) => Ok(PortState::Open),
+            Err(e) if e.kind() == io::ErrorKind::ConnectionRefused => Ok(PortState::Closed),
+            Err(e)
+                if matches!(
+                    e.kind(),
+                    io::ErrorKind::NetworkUnreachable | io::ErrorKind::HostUnreachable
+                ) =>
+            {
+                Err(Error::Unroutable {
+                    address: target,
+                    source: e,
+                })
+            }
+            Err(e) => Err(Error::Connect {
+                address: target,
+                source: e,
+            }),
+        }
+    }
+
+    /// Open a tcp connection to `target`
+    pub async fn connect(&self, target: SocketAddr) -> io::Result<TcpStream> {
+        self.socket(target)?.connect(target).await
+    }
+
+    /// Create a socket of the family of `target`, bound to the source address & interface
+    fn socket(&self, target: SocketAddr) -> io::Result<TcpSocket> {
+        let socket = match target {
+            SocketAddr::V4(_) => TcpSocket::new_v4()?,
+            SocketAddr::V6(_) => TcpSocket::new_v6()?,
+        };
+        if let Some(interface) = &self.interface {
+            bind_device(&socket, interface)?;
+        }
+        if let Some(source_ip) = self.source_ip {
+            if source_ip.is_ipv4() != target.is_ipv4() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "source address {} has another family than the target",
+                        source_ip
+                    ),
+                ));
+            }
+        }
+        if self.source_port.is_some() {
+            // Probes to different targets share the port, their 4-tuples differ
+            socket.set_reuseaddr(true)?;
+            #[cfg(unix)]
+            socket.set_reuseport(true)?;
+        }
+        if self.source_ip.is_some() || self.source_port.is_some() {
+            let unspecified = match target {
+                SocketAddr::V4(_) => Ipv4Addr::UNSPECIFIED.into(),
+                SocketAddr::V6(_) => Ipv6Addr::UNSPECIFIED.into(),
+            };
+            let source_ip = self.source_ip.unwrap_or(unspecified);
+            socket.bind(SocketAddr::new(source_ip, self.source_port.unwrap_or(0)))?;
+        }
+        Ok(socket)
+    }
+
+    /// Describe the local side of probes for error messages
+    fn describe(&self) -> String {
+        let mut local = match self.source_ip {
+            Some(ip) => ip.to_string(),
+            None => "any address".to_string(),
+        };
+        if let Some(port) = self.source_port {
+            local += &format!(" port {}", port);
+        }
+        if let Some(interface) = &self.interface {
+            local += &format!(" on {}", interface);
+        }
+        local
+    }
+}
+
+/// Bind a socket to a network interface
+#[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+fn bind_device(socket: &TcpSocket, interface: &str) -> io::Result<()> {
+    socket.bind_device(Some(interface.as_bytes()))
+}
+
+/// Bind a socket to a network interface
+#[cfg(not(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))]
+fn bind_device(_socket: &TcpSocket, _interface: &str) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "binding to an interface is not supported on this platform",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that connections originate from the source address
+    #[tokio::test]
+    async fn connect_from_source_ip() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let connector = Connector {
+            source_ip: Some("127.0.0.1".parse().unwrap()),
+            ..Default::default()
+        };
+        connector.check().unwrap();
+
+        let stream = connector
+            .connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            stream.local_addr().unwrap().ip(),
+            connector.source_ip.unwrap()
+        );
+
+        let mismatch = connector.connect("[::1]:9".parse().unwrap()).await;
+        assert_eq!(mismatch.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    /// Check that an address of another host cannot be bound
+    #[test]
+    fn check_foreign_source_ip() {
+        let connector = Connector {
+            source_ip: Some("192.0.2.1".parse().unwrap()),
+            ..Default::default()
+        };
+
+        assert!(matches!(connector.check(), Err(Error::Bind { .. })));
+    }
+
+    /// Check that concurrent probes share a fixed source port
+    #[tokio::test]
+    async fn connect_from_source_port() {
+        let first = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let second = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let source_port = {
+            let free = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            free.local_addr().unwrap().port()
+        };
+        let connector = Connector {
+            source_port: Some(source_port),
+            ..Default::default()
+        };
+        connector.check().unwrap();
+
+        let a = connector
+            .connect(first.local_addr().unwrap())
+            .await
+            .unwrap();
+        let b = connector
+            .connect(second.local_addr().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(a.local_addr().unwrap().port(), source_port);
+        assert_eq!(b.local_addr().unwrap().port(), source_port);
+    }
+}
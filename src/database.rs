@@ -0,0 +1,391 @@
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use clap::ValueEnum;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::address::display_ip;
+use crate::error::Error;
+use crate::output::Format;
+use crate::port::{PortState, Target, TargetFailure};
+
+/// Tables of the results database
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS runs (
+        id INTEGER PRIMARY KEY,
+        started INTEGER NOT NULL,
+        finished INTEGER NOT NULL,
+        complete INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS names (
+        run INTEGER NOT NULL REFERENCES runs (id),
+        address TEXT NOT NULL,
+        name TEXT NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS ports (
+        run INTEGER NOT NULL REFERENCES runs (id),
+        address TEXT NOT NULL,
+        port INTEGER NOT NULL,
+        state TEXT NOT NULL,
+        service TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS ports_by_port ON ports (port, state);
+    CREATE INDEX IF NOT EXISTS ports_by_address ON ports (address, port);
+    CREATE INDEX IF NOT EXISTS names_by_run ON names (run, address);
+";
+
+/// Results of every run, stored in an sqlite file
+pub struct Database {
+    connection: Connection,
+}
+
+/// Port states to query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum StateFilter {
+    Open,
+    Closed,
+    Filtered,
+    Any,
+}
+
+/// Which stored ports to list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query {
+    pub port: Option<u16>,
+    /// Address or any name of the host
+    pub host: Option<String>,
+    pub state: StateFilter,
+    /// Only runs started at or after this unix time
+    pub since: Option<u64>,
+    /// Only the first run each port was seen in
+    pub first_seen: bool,
+}
+
+/// A port of a host in a stored run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Sighting {
+    /// Start of the run, in seconds since the unix epoch
+    pub time: u64,
+    pub address: String,
+    pub names: Vec<String>,
+    pub port: u16,
+    pub state: String,
+    pub service: String,
+}
+
+/// Name of a port state in the database
+fn state_name(state: PortState) -> &'static str {
+    match state {
+        PortState::Unknown => "unknown",
+        PortState::Open => "open",
+        PortState::Closed => "closed",
+        PortState::Filtered => "filtered",
+    }
+}
+
+impl Database {
+    /// Open or create a results database
+    pub fn open(path: &Path) -> Result<Database, Error> {
+        let error = |e: rusqlite::Error| Error::Database {
+            path: path.to_owned(),
+            reason: e.to_string(),
+        };
+        let connection = Connection::open(path).map_err(error)?;
+        connection.execute_batch(SCHEMA).map_err(error)?;
+        Ok(Database { connection })
+    }
+
+    /// Store the probed ports of a run, returning its id
+    pub fn record_run(
+        &mut self,
+        started: u64,
+        finished: u64,
+        complete: bool,
+        results: &[Result<Target, TargetFailure>],
+    ) -> Result<i64, Error> {
+        self.record(started, finished, complete, results)
+            .map_err(|e| self.error(e))
+    }
+
+    /// Store the probed ports of a run in one transaction
+    fn record(
+        &mut self,
+        started: u64,
+        finished: u64,
+        complete: bool,
+        results: &[Result<Target, TargetFailure>],
+    ) -> rusqlite::Result<i64> {
+        let transaction = self.connection.transaction()?;
+        transaction.execute(
+            "INSERT INTO runs (started, finished, complete) VALUES (?1, ?2, ?3)",
+            params![started, finished, complete],
+        )?;
+        let run = transaction.last_insert_rowid();
+        {
+            let mut names = transaction
+                .prepare("INSERT INTO names (run, address, name) VALUES (?1, ?2, ?3)")?;
+            let mut ports = transaction.prepare(
+                "INSERT INTO ports (run, address, port, state, service) \
+                 VALUES (?1, ?2, ?3, ?4, ?5)",
+            )?;
+            for target in results.iter().filter_map(|result| result.as_ref().ok()) {
+                let address = display_ip(&target.address);
+                for name in target.names.iter().chain(target.hostname.iter()) {
+                    names.execute(params![run, address, name])?;
+                }
+                for (index, port) in target.ports.iter().enumerate() {
+                    let state = target.states.get(index);
+                    if state != PortState::Unknown {
+                        ports.execute(params![
+                            run,
+                            address,
+                            port.number,
+                            state_name(state),
+                            port.service
+                        ])?;
+                    }
+                }
+            }
+        }
+        transaction.commit()?;
+        Ok(run)
+    }
+
+    /// List stored ports matching `query`, oldest first
+    pub fn query(&self, query: &Query) -> Result<Vec<Sighting>, Error> {
+        self.select(query).map_err(|e| self.error(e))
+    }
+
+    /// Select stored ports, the first sighting of each port of a host when grouped
+    fn select(&self, query: &Query) -> rusqlite::Result<Vec<Sighting>> {
+        let time = if query.first_seen {
+            "MIN(r.started)"
+        } else {
+            "r.started"
+        };
+        let group = if query.first_seen {
+            "GROUP BY p.address, p.port"
+        } else {
+            ""
+        };
+        let sql = format!(
+            "SELECT {}, p.address, \
+                 (SELECT group_concat(n.name, char(10)) FROM names n \
+                  WHERE n.run = p.run AND n.address = p.address), \
+                 p.port, p.state, p.service \
+             FROM ports p JOIN runs r ON r.id = p.run \
+             WHERE (?1 IS NULL OR p.port = ?1) \
+               AND (?2 IS NULL OR p.state = ?2) \
+               AND (?3 IS NULL OR r.started >= ?3) \
+               AND (?4 IS NULL OR p.address = ?4 OR EXISTS ( \
+                   SELECT 1 FROM names n WHERE n.run = p.run AND n.address = p.address \
+                   AND n.name = ?4 COLLATE NOCASE)) \
+             {} ORDER BY 1, p.address, p.port",
+            time, group
+        );
+        let state = match query.state {
+            StateFilter::Open => Some("open"),
+            StateFilter::Closed => Some("closed"),
+            StateFilter::Filtered => Some("filtered"),
+            StateFilter::Any => None,
+        };
+
+        let mut statement = self.connection.prepare(&sql)?;
+        let rows =
+            statement.query_map(params![query.port, state, query.since, query.host], |row| {
+                let names: Option<String> = row.get(2)?;
+                Ok(Sighting {
+                    time: row.get(0)?,
+                    address: row.get(1)?,
+                    names: names
+                        .map(|names| names.lines().map(str::to_string).collect())
+                        .unwrap_or_default(),
+                    port: row.get(3)?,
+                    state: row.get(4)?,
+                    service: row.get(5)?,
+                })
+            })?;
+        rows.collect()
+    }
+
+    /// Error of the database file
+    fn error(&self, e: rusqlite::Error) -> Error {
+        Error::Database {
+            path: self.connection.path().unwrap_or("").into(),
+            reason: e.to_string(),
+        }
+    }
+}
+
+/// Current unix time in seconds
+pub fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_secs())
+}
+
+/// Parse an age like `90s`, `30m`, `12h`, `7d` or `2w` into seconds
+pub fn parse_age(s: &str) -> Result<u64, String> {
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (amount, unit) = s.split_at(split);
+    let amount: u64 = amount.parse().map_err(|_| format!("invalid age '{}'", s))?;
+    let unit = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(format!("unknown unit '{}' in age '{}'", unit, s)),
+    };
+    amount
+        .checked_mul(unit)
+        .ok_or_else(|| format!("age '{}' is too long", s))
+}
+
+/// Format a unix time as an RFC 3339 UTC timestamp
+pub fn format_time(time: u64) -> String {
+    let (days, seconds) = (time / 86400, time % 86400);
+    // Civil date from days since 1970-01-01, valid for the proleptic Gregorian calendar
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// Write sightings in `format`
+pub fn write_sightings(
+    out: &mut dyn Write,
+    format: Format,
+    sightings: &[Sighting],
+) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            if sightings.is_empty() {
+                return writeln!(out, "No ports found");
+            }
+            for sighting in sightings.iter() {
+                writeln!(
+                    out,
+                    "{}  {} ({})  {}\t{}\t{}",
+                    format_time(sighting.time),
+                    sighting.address,
+                    sighting.names.join(", "),
+                    sighting.port,
+                    sighting.state,
+                    sighting.service
+                )?;
+            }
+            Ok(())
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, sightings)?;
+            writeln!(out)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::Port;
+    use std::sync::Arc;
+
+    /// Target with the given port states
+    fn target(name: &str, address: &str, ports: &[(u16, PortState)]) -> Target {
+        let list: Arc<[Port]> = ports
+            .iter()
+            .map(|&(number, _)| Port {
+                service: "ms-wbt-server",
+                number,
+            })
+            .collect();
+        let mut target = Target::new(name.to_string(), address.parse().unwrap(), list);
+        for (index, &(_, state)) in ports.iter().enumerate() {
+            target.states.set(index, state);
+        }
+        target
+    }
+
+    /// Check that stored runs are found by port, state, host & time
+    #[test]
+    fn record_and_query() {
+        let path = std::env::temp_dir().join(format!("results-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let mut database = Database::open(&path).unwrap();
+        let first = vec![Ok(target(
+            "dc1",
+            "192.0.2.1:0",
+            &[(3389, PortState::Closed)],
+        ))];
+        let second = vec![
+            Ok(target("dc1", "192.0.2.1:0", &[(3389, PortState::Open)])),
+            Ok(target("ws7", "192.0.2.7:0", &[(3389, PortState::Open)])),
+        ];
+        let third = vec![Ok(target(
+            "dc1",
+            "192.0.2.1:0",
+            &[(3389, PortState::Open), (80, PortState::Unknown)],
+        ))];
+        database.record_run(100, 110, true, &first).unwrap();
+        database.record_run(200, 210, true, &second).unwrap();
+        database.record_run(300, 310, false, &third).unwrap();
+
+        let mut query = Query {
+            port: Some(3389),
+            host: None,
+            state: StateFilter::Open,
+            since: Some(150),
+            first_seen: false,
+        };
+        let found = database.query(&query).unwrap();
+        assert_eq!(found.len(), 3);
+        assert_eq!(found[1].names, vec!["ws7"]);
+
+        query.host = Some("DC1".to_string());
+        query.first_seen = true;
+        query.since = None;
+        let found = database.query(&query).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(
+            (found[0].time, found[0].address.as_str()),
+            (200, "192.0.2.1")
+        );
+
+        query.state = StateFilter::Any;
+        query.port = None;
+        assert_eq!(database.query(&query).unwrap()[0].time, 100);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    /// Check ages & timestamps
+    #[test]
+    fn ages_and_times() {
+        assert_eq!(parse_age("7d"), Ok(604800));
+        assert_eq!(parse_age("90"), Ok(90));
+        assert!(parse_age("1y").is_err());
+        assert!(parse_age("99999999999999999w").is_err());
+        assert_eq!(format_time(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_time(1709251199), "2024-02-29T23:59:59Z");
+    }
+}
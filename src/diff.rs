@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::net::SocketAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::address::display_ip;
+use crate::output::{Format, PortReport};
+use crate::port::{Port, Target};
+
+/// Changes between two scans of the same targets
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Diff {
+    /// Hosts with open ports only in the new scan
+    pub appeared: Vec<HostDiff>,
+    /// Hosts with open ports only in the old scan
+    pub disappeared: Vec<HostDiff>,
+    /// Hosts with open ports in both scans, but other ones or services
+    pub changed: Vec<HostDiff>,
+}
+
+/// Changes of a host, ports newly open & no longer open
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostDiff {
+    pub address: String,
+    pub names: Vec<String>,
+    pub opened: Vec<PortReport>,
+    pub closed: Vec<PortReport>,
+    pub services: Vec<ServiceChange>,
+}
+
+/// A port open in both scans with another service name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServiceChange {
+    pub number: u16,
+    pub old: String,
+    pub new: String,
+}
+
+impl Diff {
+    /// Check if nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.appeared.is_empty() && self.disappeared.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl HostDiff {
+    /// Host without changes
+    fn new(target: &Target) -> HostDiff {
+        HostDiff {
+            address: display_ip(&target.address),
+            names: target.names.clone(),
+            opened: Vec::new(),
+            closed: Vec::new(),
+            services: Vec::new(),
+        }
+    }
+
+    /// Check if nothing changed
+    fn is_empty(&self) -> bool {
+        self.opened.is_empty() && self.closed.is_empty() && self.services.is_empty()
+    }
+}
+
+/// Report of a port
+fn port_report(port: Port) -> PortReport {
+    PortReport {
+        number: port.number,
+        service: port.service.to_string(),
+    }
+}
+
+/// Compare the open ports of targets in an old & a new scan, matched by address
+///
+/// Only hosts with open ports count as present, since scans of networks contain
+/// every address.
+pub fn diff_targets(old: &[Target], new: &[Target]) -> Diff {
+    let present = |target: &&Target| target.open_ports().next().is_some();
+    let old: Vec<&Target> = old.iter().filter(present).collect();
+    let new: Vec<&Target> = new.iter().filter(present).collect();
+    let old_targets: HashMap<SocketAddr, &Target> =
+        old.iter().map(|target| (target.address, *target)).collect();
+    let new_targets: HashMap<SocketAddr, &Target> =
+        new.iter().map(|target| (target.address, *target)).collect();
+    let mut diff = Diff::default();
+
+    for target in new.iter() {
+        match old_targets.get(&target.address) {
+            Some(previous) => {
+                let previous_ports: HashMap<u16, Port> = previous
+                    .open_ports()
+                    .map(|port| (port.number, port))
+                    .collect();
+                let ports: HashMap<u16, Port> = target
+                    .open_ports()
+                    .map(|port| (port.number, port))
+                    .collect();
+
+                let mut host = HostDiff::new(target);
+                for port in target.open_ports() {
+                    match previous_ports.get(&port.number) {
+                        None => host.opened.push(port_report(port)),
+                        Some(previous) if previous.service != port.service => {
+                            host.services.push(ServiceChange {
+                                number: port.number,
+                                old: previous.service.to_string(),
+                                new: port.service.to_string(),
+                            })
+                        }
+                        Some(_) => {}
+                    }
+                }
+                for port in previous.open_ports() {
+                    if !ports.contains_key(&port.number) {
+                        host.closed.push(port_report(port));
+                    }
+                }
+                if !host.is_empty() {
+                    diff.changed.push(host);
+                }
+            }
+            None => {
+                let mut host = HostDiff::new(target);
+                host.opened = target.open_ports().map(port_report).collect();
+                diff.appeared.push(host);
+            }
+        }
+    }
+    for target in old.iter() {
+        if !new_targets.contains_key(&target.address) {
+            let mut host = HostDiff::new(target);
+            host.closed = target.open_ports().map(port_report).collect();
+            diff.disappeared.push(host);
+        }
+    }
+    diff
+}
+
+/// Write the changes between two scans in `format`
+pub fn write_diff(out: &mut dyn Write, format: Format, diff: &Diff) -> io::Result<()> {
+    match format {
+        Format::Text => write_text(out, diff),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, diff)?;
+            writeln!(out)
+        }
+    }
+}
+
+/// Write the changes between two scans as text
+fn write_text(out: &mut dyn Write, diff: &Diff) -> io::Result<()> {
+    if diff.is_empty() {
+        return writeln!(out, "No changes");
+    }
+    let sections = [
+        ("Appeared", &diff.appeared),
+        ("Disappeared", &diff.disappeared),
+        ("Changed", &diff.changed),
+    ];
+    for (title, hosts) in sections {
+        for host in hosts.iter() {
+            writeln!(
+                out,
+                "{} {} ({}):",
+                title,
+                host.address,
+                host.names.join(", ")
+            )?;
+            for port in host.opened.iter() {
+                writeln!(out, "  + {}\t{}", port.number, port.service)?;
+            }
+            for port in host.closed.iter() {
+                writeln!(out, "  - {}\t{}", port.number, port.service)?;
+            }
+            for change in host.services.iter() {
+                writeln!(
+                    out,
+                    "  ~ {}\t{} -> {}",
+                    change.number, change.old, change.new
+                )?;
+            }
+            writeln!(out)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::PortState;
+    use std::sync::Arc;
+
+    /// Target with all given ports open
+    fn target(address: &str, ports: &[(u16, &'static str)]) -> Target {
+        let ports: Arc<[Port]> = ports
+            .iter()
+            .map(|&(number, service)| Port { service, number })
+            .collect();
+        let mut target = Target::new(address.to_string(), address.parse().unwrap(), ports);
+        for index in 0..target.ports.len() {
+            target.states.set(index, PortState::Open);
+        }
+        target
+    }
+
+    /// Check that appeared & disappeared hosts and port changes are found
+    #[test]
+    fn diff_scans() {
+        let old = vec![
+            target(
+                "192.0.2.1:0",
+                &[(22, "ssh"), (80, "http"), (8080, "http-proxy")],
+            ),
+            target("192.0.2.2:0", &[(443, "https")]),
+            target("192.0.2.4:0", &[(25, "smtp")]),
+        ];
+        let new = vec![
+            target(
+                "192.0.2.1:0",
+                &[(22, "ssh"), (443, "https"), (8080, "http-alt")],
+            ),
+            target("192.0.2.3:0", &[(3389, "ms-wbt-server")]),
+            target("192.0.2.4:0", &[(25, "smtp")]),
+        ];
+
+        let diff = diff_targets(&old, &new);
+        assert_eq!(diff.appeared.len(), 1);
+        assert_eq!(diff.appeared[0].address, "192.0.2.3");
+        assert_eq!(diff.appeared[0].opened[0].number, 3389);
+        assert_eq!(diff.disappeared[0].address, "192.0.2.2");
+        assert_eq!(diff.disappeared[0].closed[0].number, 443);
+        assert_eq!(diff.changed.len(), 1);
+
+        let mut text = Vec::new();
+        write_diff(&mut text, Format::Text, &diff).unwrap();
+        let text = String::from_utf8(text).unwrap();
+        assert!(text.contains(
+            "Changed 192.0.2.1 (192.0.2.1:0):\n  + 443\thttps\n  - 80\thttp\n  \
+             ~ 8080\thttp-proxy -> http-alt\n"
+        ));
+    }
+
+    /// Check that hosts of scanned networks appear & disappear with their open ports
+    #[test]
+    fn diff_network_scans() {
+        let old = vec![
+            target("192.0.2.1:0", &[(22, "ssh")]),
+            target("192.0.2.2:0", &[]),
+        ];
+        let new = vec![
+            target("192.0.2.1:0", &[]),
+            target("192.0.2.2:0", &[(80, "http")]),
+        ];
+
+        let diff = diff_targets(&old, &new);
+        assert_eq!(diff.appeared[0].address, "192.0.2.2");
+        assert_eq!(diff.disappeared[0].address, "192.0.2.1");
+        assert!(diff.changed.is_empty());
+    }
+
+    /// Check that identical scans have no changes
+    #[test]
+    fn diff_unchanged() {
+        let scan = vec![target("192.0.2.1:0", &[(22, "ssh")])];
+        let diff = diff_targets(&scan, &scan);
+
+        let mut json = Vec::new();
+        write_diff(&mut json, Format::Json, &diff).unwrap();
+        let parsed: Diff = serde_json::from_slice(&json).unwrap();
+        assert!(parsed.is_empty());
+    }
+}
@@ -0,0 +1,387 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, Write};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+use futures::future::join_all;
+use futures::stream::{self, StreamExt};
+
+use crate::address::{display_ip, TargetSpec};
+use crate::dns::{Record, RecordType, Resolver};
+use crate::error::Error;
+use crate::output::{write_report, Format, Report};
+use crate::port::{Port, Target, TargetFailure};
+
+/// Options of the discovery stage
+#[derive(Debug, Clone, Default)]
+pub struct DiscoveryOptions {
+    /// Add services advertised in SRV records of host names
+    pub srv: bool,
+    /// Words to brute-force subdomains of host names with
+    pub subdomains: Vec<String>,
+    /// Maximum amount of concurrent subdomain lookups
+    pub concurrency: usize,
+}
+
+/// Targets found by the discovery stage & inputs that could not be resolved
+#[derive(Debug, Default)]
+pub struct Discovery {
+    pub targets: Vec<Target>,
+    pub failures: Vec<TargetFailure>,
+}
+
+impl Discovery {
+    /// Add a target per address of a name or record why it failed
+    fn add(&mut self, name: &str, addresses: Result<Vec<SocketAddr>, Error>, ports: &Arc<[Port]>) {
+        match addresses {
+            Ok(addresses) => {
+                for address in addresses {
+                    self.targets
+                        .push(Target::new(name.to_string(), address, ports.clone()));
+                }
+            }
+            Err(error) => self.failures.push(TargetFailure {
+                name: name.to_string(),
+                error,
+            }),
+        }
+    }
+}
+
+/// Turn inputs into targets to scan `ports` on
+///
+/// Literal addresses & networks are used as is, host names are resolved and,
+/// depending on `options`, extended with SRV endpoints & brute-forced subdomains.
+pub async fn discover_targets(
+    inputs: &[String],
+    ports: Arc<[Port]>,
+    resolver: &Resolver,
+    options: &DiscoveryOptions,
+) -> Discovery {
+    let mut discovery = Discovery::default();
+    let mut domains = Vec::new();
+    for input in inputs.iter() {
+        let addresses = match input.parse::<TargetSpec>() {
+            Ok(TargetSpec::Host(host)) => {
+                domains.push(host.clone());
+                resolve_socket_addrs(resolver, &host).await
+            }
+            Ok(spec) => Ok(spec.addresses().expect("Literal targets have addresses")),
+            Err(reason) => Err(Error::InvalidTarget {
+                target: input.to_string(),
+                reason,
+            }),
+        };
+        discovery.add(input, addresses, &ports);
+    }
+
+    for domain in domains.iter() {
+        // Services advertised in SRV records
+        if options.srv {
+            for endpoint in discover_srv(resolver, domain).await {
+                let port: Arc<[Port]> = Arc::new([Port {
+                    service: endpoint.service,
+                    number: endpoint.port,
+                }]);
+                let addresses = resolve_socket_addrs(resolver, &endpoint.host).await;
+                discovery.add(&endpoint.host, addresses, &port);
+            }
+        }
+
+        // Subdomains from a wordlist
+        if !options.subdomains.is_empty() {
+            let found =
+                brute_force_subdomains(resolver, domain, &options.subdomains, options.concurrency)
+                    .await;
+            for (name, addresses) in found {
+                let addresses = addresses.into_iter().map(|ip| SocketAddr::new(ip, 0));
+                discovery.add(&name, Ok(addresses.collect()), &ports);
+            }
+        }
+    }
+
+    discovery
+}
+
+/// Resolve a host name into socket addresses
+async fn resolve_socket_addrs(resolver: &Resolver, host: &str) -> Result<Vec<SocketAddr>, Error> {
+    let addresses = resolver.resolve(host).await?;
+    Ok(addresses
+        .into_iter()
+        .map(|ip| SocketAddr::new(ip, 0))
+        .collect())
+}
+
+/// Resolve `word.domain` for every word, with at most `concurrency` lookups at once
+///
+/// Names that only resolve to the answers of a wildcard record are skipped.
+pub async fn brute_force_subdomains(
+    resolver: &Resolver,
+    domain: &str,
+    words: &[String],
+    concurrency: usize,
+) -> Vec<(String, Vec<IpAddr>)> {
+    let domain = domain.trim_end_matches('.');
+    let wildcard = wildcard_addresses(resolver, domain).await;
+
+    // Collected, so the lookups can be awaited in spawned tasks
+    let lookups: Vec<_> = words
+        .iter()
+        .map(|word| async move {
+            let name = format!("{}.{}", word, domain);
+            let addresses = resolver.resolve(&name).await;
+            (name, addresses)
+        })
+        .collect();
+    stream::iter(lookups)
+        .buffer_unordered(concurrency.max(1))
+        .filter_map(|(name, addresses)| {
+            let found = match addresses {
+                Ok(addresses) if !addresses.iter().all(|a| wildcard.contains(a)) => {
+                    Some((name, addresses))
+                }
+                _ => None,
+            };
+            async move { found }
+        })
+        .collect()
+        .await
+}
+
+/// Addresses a domain answers with for names that do not exist
+///
+/// Empty when the domain has no wildcard record.
+async fn wildcard_addresses(resolver: &Resolver, domain: &str) -> HashSet<IpAddr> {
+    let mut addresses = HashSet::new();
+    for _ in 0..2 {
+        let label = RandomState::new().build_hasher().finish();
+        let name = format!("wildcard-check-{:016x}.{}", label, domain);
+        if let Ok(found) = resolver.resolve(&name).await {
+            addresses.extend(found);
+        }
+    }
+    addresses
+}
+
+/// Well-known tcp services advertised through `_service._tcp` SRV records
+const SRV_SERVICES: &[&str] = &[
+    "autodiscover",
+    "caldav",
+    "caldavs",
+    "carddav",
+    "carddavs",
+    "gc",
+    "h323cs",
+    "imap",
+    "imaps",
+    "jabber",
+    "kerberos",
+    "kpasswd",
+    "ldap",
+    "ldaps",
+    "matrix",
+    "minecraft",
+    "pop3",
+    "pop3s",
+    "sip",
+    "sips",
+    "stun",
+    "submission",
+    "turn",
+    "xmpp-client",
+    "xmpp-server",
+];
+
+/// A service endpoint advertised in a SRV record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SrvEndpoint {
+    pub service: &'static str,
+    pub host: String,
+    pub port: u16,
+}
+
+/// Query the well-known SRV names of a domain, concurrently
+///
+/// Names without records are skipped, as are records whose target is `.`,
+/// which marks a service as unavailable.
+pub async fn discover_srv(resolver: &Resolver, domain: &str) -> Vec<SrvEndpoint> {
+    let domain = domain.trim_end_matches('.');
+    let queries = SRV_SERVICES.iter().map(|service| async move {
+        let name = format!("_{}._tcp.{}", service, domain);
+        let records = resolver
+            .query(&name, RecordType::Srv)
+            .await
+            .unwrap_or_default();
+        records
+            .into_iter()
+            .filter_map(|record| match record {
+                Record::Srv { port, target, .. } if !target.is_empty() => Some(SrvEndpoint {
+                    service,
+                    host: target,
+                    port,
+                }),
+                _ => None,
+            })
+            .collect::<Vec<SrvEndpoint>>()
+    });
+
+    let mut endpoints: Vec<SrvEndpoint> = join_all(queries).await.into_iter().flatten().collect();
+    endpoints.dedup();
+    endpoints
+}
+
+/// Write discovered hosts in `format`, with the ports advertised in SRV records
+///
+/// The json format is a report without open ports, like the results of a scan.
+pub fn write_hosts(
+    out: &mut dyn Write,
+    format: Format,
+    results: &[Result<Target, TargetFailure>],
+) -> io::Result<()> {
+    if format == Format::Json {
+        return write_report(out, format, &Report::new(results, true));
+    }
+    for result in results.iter() {
+        match result {
+            Ok(target) => {
+                match &target.hostname {
+                    Some(hostname) => writeln!(
+                        out,
+                        "{} ({}, ptr: {})",
+                        display_ip(&target.address),
+                        target.names.join(", "),
+                        hostname
+                    )?,
+                    None => writeln!(
+                        out,
+                        "{} ({})",
+                        display_ip(&target.address),
+                        target.names.join(", ")
+                    )?,
+                }
+                for port in target.ports.iter() {
+                    writeln!(out, "  {}\t{}", port.number, port.service)?;
+                }
+            }
+            Err(failure) => writeln!(
+                out,
+                "Discovery failed for {}: {}",
+                failure.name, failure.error
+            )?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dns::{stand_in, Family};
+    use std::net::Ipv4Addr;
+
+    /// Check that advertised endpoints are found and unavailable services skipped
+    #[tokio::test]
+    async fn discover_srv_endpoints() {
+        let server = stand_in::spawn(vec![
+            (
+                "_xmpp-client._tcp.example.test",
+                Record::Srv {
+                    priority: 5,
+                    weight: 0,
+                    port: 5223,
+                    target: "chat.example.test".to_string(),
+                },
+            ),
+            (
+                "_ldap._tcp.example.test",
+                Record::Srv {
+                    priority: 0,
+                    weight: 100,
+                    port: 3890,
+                    target: "dc1.example.test".to_string(),
+                },
+            ),
+            (
+                "_imap._tcp.example.test",
+                Record::Srv {
+                    priority: 0,
+                    weight: 0,
+                    port: 0,
+                    target: String::new(),
+                },
+            ),
+        ])
+        .await;
+        let resolver = Resolver::new(Family::Any, true, Some(server));
+
+        let mut endpoints = discover_srv(&resolver, "example.test").await;
+        endpoints.sort_by_key(|endpoint| endpoint.port);
+        assert_eq!(
+            endpoints,
+            vec![
+                SrvEndpoint {
+                    service: "ldap",
+                    host: "dc1.example.test".to_string(),
+                    port: 3890,
+                },
+                SrvEndpoint {
+                    service: "xmpp-client",
+                    host: "chat.example.test".to_string(),
+                    port: 5223,
+                },
+            ]
+        );
+    }
+
+    /// Check subdomain brute-forcing with & without wildcard dns
+    #[tokio::test]
+    async fn brute_force_with_wildcard() {
+        let server = stand_in::spawn(vec![
+            ("www.example.test", Record::A(Ipv4Addr::new(192, 0, 2, 1))),
+            ("mail.example.test", Record::A(Ipv4Addr::new(192, 0, 2, 2))),
+            ("*.wild.test", Record::A(Ipv4Addr::new(198, 51, 100, 1))),
+            ("www.wild.test", Record::A(Ipv4Addr::new(198, 51, 100, 2))),
+        ])
+        .await;
+        let resolver = Resolver::new(Family::Any, true, Some(server));
+        let words: Vec<String> = ["www", "mail", "nope"].map(String::from).to_vec();
+
+        let mut found = brute_force_subdomains(&resolver, "example.test", &words, 2).await;
+        found.sort();
+        let names: Vec<&str> = found.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["mail.example.test", "www.example.test"]);
+
+        let found = brute_force_subdomains(&resolver, "wild.test", &words, 2).await;
+        let names: Vec<&str> = found.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["www.wild.test"]);
+    }
+
+    /// Check that the discovery stage combines inputs, failures & subdomains
+    #[tokio::test]
+    async fn discover_targets_stage() {
+        let server = stand_in::spawn(vec![
+            ("example.test", Record::A(Ipv4Addr::new(192, 0, 2, 1))),
+            ("www.example.test", Record::A(Ipv4Addr::new(192, 0, 2, 2))),
+        ])
+        .await;
+        let resolver = Resolver::new(Family::Any, true, Some(server));
+        let inputs = ["example.test", "192.0.2.9", "missing.test"].map(String::from);
+        let options = DiscoveryOptions {
+            srv: false,
+            subdomains: vec!["www".to_string()],
+            concurrency: 4,
+        };
+
+        let discovery = discover_targets(&inputs, Arc::new([]), &resolver, &options).await;
+        let names: Vec<&str> = discovery
+            .targets
+            .iter()
+            .map(|target| target.names[0].as_str())
+            .collect();
+        assert_eq!(names, vec!["example.test", "192.0.2.9", "www.example.test"]);
+        assert_eq!(discovery.failures.len(), 1);
+        assert_eq!(discovery.failures[0].name, "missing.test");
+    }
+}
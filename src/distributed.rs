@@ -0,0 +1,656 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::ffi::CStr;
+use std::io;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::Notify;
+use tokio::time::{sleep, Duration, Instant};
+use tokio_util::sync::CancellationToken;
+
+use crate::address::{display_ip, TargetSpec};
+use crate::common_ports::intern_name;
+use crate::connect::Connector;
+use crate::dns::Resolver;
+use crate::error::Error;
+use crate::http::{read_request, send_json, write_response, Url};
+use crate::output::{PortReport, Report};
+use crate::port::{scan_targets, Port, ScanHooks, Target};
+
+/// Time between requests of an idle worker
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Delay before retrying a failed request to the coordinator, doubled per attempt
+const RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Attempts of a request to the coordinator before the worker gives up
+const ATTEMPTS: u32 = 6;
+
+/// Time a finished coordinator keeps telling polling workers to stop
+const LINGER: Duration = Duration::from_secs(3);
+
+/// Targets handed to one worker at a time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkUnit {
+    pub id: u64,
+    pub targets: Vec<UnitTarget>,
+    /// Milliseconds the unit stays assigned without a heartbeat
+    pub lease_ms: u64,
+}
+
+/// A target address of a work unit & its ports, which differ e.g. for SRV endpoints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnitTarget {
+    pub address: String,
+    pub names: Vec<String>,
+    pub ports: Vec<PortReport>,
+}
+
+/// Body of the requests of a worker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct WorkerRequest {
+    worker: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    report: Option<Report>,
+}
+
+/// Split targets into work units of at most `size` targets
+pub fn work_units(targets: &[Target], size: usize) -> Vec<WorkUnit> {
+    targets
+        .chunks(size.max(1))
+        .zip(1..)
+        .map(|(chunk, id)| WorkUnit {
+            id,
+            targets: chunk
+                .iter()
+                .map(|target| UnitTarget {
+                    address: display_ip(&target.address),
+                    names: target.names.clone(),
+                    ports: target
+                        .ports
+                        .iter()
+                        .map(|port| PortReport {
+                            number: port.number,
+                            service: port.service.to_string(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            lease_ms: 0,
+        })
+        .collect()
+}
+
+/// A work unit assigned to a worker
+#[derive(Debug)]
+struct Lease {
+    unit: WorkUnit,
+    worker: String,
+    deadline: Instant,
+}
+
+/// Work units by state
+#[derive(Debug, Default)]
+struct Units {
+    pending: VecDeque<WorkUnit>,
+    leased: HashMap<u64, Lease>,
+    done: BTreeMap<u64, Report>,
+    total: usize,
+}
+
+impl Units {
+    /// Check if every unit was scanned
+    fn is_finished(&self) -> bool {
+        self.done.len() == self.total
+    }
+}
+
+/// Hands out work units to workers & collects their results
+pub struct Coordinator {
+    units: Mutex<Units>,
+    lease: Duration,
+    linger: Duration,
+    finished: Notify,
+}
+
+impl Coordinator {
+    /// Coordinator of `units`, reassigned when a worker misses heartbeats for `lease`
+    pub fn new(units: Vec<WorkUnit>, lease: Duration) -> Coordinator {
+        Coordinator {
+            units: Mutex::new(Units {
+                total: units.len(),
+                pending: units.into(),
+                ..Units::default()
+            }),
+            lease,
+            linger: LINGER,
+            finished: Notify::new(),
+        }
+    }
+
+    /// Assign the next unit to `worker`, reassigning units of dead workers first
+    ///
+    /// `Err` when all units are scanned, `Ok(None)` when all are assigned.
+    fn take(&self, worker: &str) -> Result<Option<WorkUnit>, ()> {
+        let mut units = self.units.lock().expect("Units poisoned");
+        if units.is_finished() {
+            return Err(());
+        }
+        let now = Instant::now();
+        let expired: Vec<u64> = units
+            .leased
+            .iter()
+            .filter(|(_, lease)| lease.deadline <= now)
+            .map(|(&id, _)| id)
+            .collect();
+        for id in expired {
+            let lease = units.leased.remove(&id).expect("Expired lease exists");
+            eprintln!("Reassigning unit {} of worker {}", id, lease.worker);
+            units.pending.push_front(lease.unit);
+        }
+
+        let Some(mut unit) = units.pending.pop_front() else {
+            return Ok(None);
+        };
+        unit.lease_ms = self.lease.as_millis() as u64;
+        units.leased.insert(
+            unit.id,
+            Lease {
+                unit: unit.clone(),
+                worker: worker.to_string(),
+                deadline: now + self.lease,
+            },
+        );
+        Ok(Some(unit))
+    }
+
+    /// Extend the lease of a unit, false when it is no longer assigned to `worker`
+    fn heartbeat(&self, id: u64, worker: &str) -> bool {
+        let mut units = self.units.lock().expect("Units poisoned");
+        match units.leased.get_mut(&id) {
+            Some(lease) if lease.worker == worker => {
+                lease.deadline = Instant::now() + self.lease;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Store the results of a unit, the first complete ones count
+    ///
+    /// Incomplete results of an interrupted worker put the unit back into the queue.
+    fn finish(&self, id: u64, worker: &str, report: Report) {
+        let mut units = self.units.lock().expect("Units poisoned");
+        if units.done.contains_key(&id) {
+            return;
+        }
+        if !report.complete {
+            if units
+                .leased
+                .get(&id)
+                .is_some_and(|lease| lease.worker == worker)
+            {
+                let lease = units.leased.remove(&id).expect("Lease exists");
+                units.pending.push_front(lease.unit);
+            }
+            return;
+        }
+        let known = units.leased.remove(&id).is_some() || {
+            let queued = units.pending.len();
+            units.pending.retain(|unit| unit.id != id);
+            units.pending.len() != queued
+        };
+        if known {
+            units.done.insert(id, report);
+            if units.is_finished() {
+                self.finished.notify_one();
+            }
+        }
+    }
+
+    /// Merged results of all scanned units, incomplete when units are missing
+    fn report(&self) -> Report {
+        let units = self.units.lock().expect("Units poisoned");
+        let mut report = Report {
+            complete: units.is_finished(),
+            targets: Vec::new(),
+            failures: Vec::new(),
+            violations: None,
+        };
+        for unit in units.done.values() {
+            report.targets.extend(unit.targets.iter().cloned());
+            report.failures.extend(unit.failures.iter().cloned());
+        }
+        report
+    }
+}
+
+/// Serve work units on `listener` until all are scanned or cancelled, returning the merged results
+///
+/// - `POST /units` assigns a unit: 200 with the unit, 204 when none is free, 410 when all are scanned
+/// - `POST /units/ID/heartbeat` extends the lease of a unit, 409 when it was reassigned
+/// - `POST /units/ID/results` stores the results of a unit as json report
+pub async fn coordinate(
+    listener: TcpListener,
+    coordinator: Arc<Coordinator>,
+    cancel: &CancellationToken,
+) -> io::Result<Report> {
+    let mut linger = None;
+    loop {
+        let (client, _) = if coordinator
+            .units
+            .lock()
+            .expect("Units poisoned")
+            .is_finished()
+        {
+            // Tell polling workers to stop for a while
+            let deadline = *linger.get_or_insert_with(|| Instant::now() + coordinator.linger);
+            tokio::select! {
+                accepted = listener.accept() => accepted?,
+                _ = tokio::time::sleep_until(deadline) => break,
+                _ = cancel.cancelled() => break,
+            }
+        } else {
+            tokio::select! {
+                accepted = listener.accept() => accepted?,
+                _ = coordinator.finished.notified() => continue,
+                _ = cancel.cancelled() => break,
+            }
+        };
+        let coordinator = coordinator.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle(&coordinator, client).await {
+                eprintln!("Responding to worker failed: {}", e);
+            }
+        });
+    }
+    Ok(coordinator.report())
+}
+
+/// Answer a worker request
+async fn handle(coordinator: &Coordinator, mut client: TcpStream) -> io::Result<()> {
+    let request = match read_request(&mut client).await {
+        Ok(request) => request,
+        Err(e) => {
+            return write_response(&mut client, 400, "text/plain", e.to_string().as_bytes()).await
+        }
+    };
+    let body: WorkerRequest = match serde_json::from_slice(&request.body) {
+        Ok(body) => body,
+        Err(e) => {
+            return write_response(&mut client, 400, "text/plain", e.to_string().as_bytes()).await
+        }
+    };
+    let segments: Vec<&str> = request.path.split('/').filter(|s| !s.is_empty()).collect();
+    let id = segments.get(1).and_then(|id| id.parse::<u64>().ok());
+
+    let (status, response) = match (request.method.as_str(), &segments[..], id) {
+        ("POST", ["units"], None) => match coordinator.take(&body.worker) {
+            Ok(Some(unit)) => (200, serde_json::to_vec(&unit).expect("Units serialize")),
+            Ok(None) => (204, Vec::new()),
+            Err(()) => (410, Vec::new()),
+        },
+        ("POST", ["units", _, "heartbeat"], Some(id)) => {
+            match coordinator.heartbeat(id, &body.worker) {
+                true => (200, Vec::new()),
+                false => (409, Vec::new()),
+            }
+        }
+        ("POST", ["units", _, "results"], Some(id)) => match body.report {
+            Some(report) => {
+                coordinator.finish(id, &body.worker, report);
+                (200, Vec::new())
+            }
+            None => (400, b"missing report".to_vec()),
+        },
+        _ => (404, Vec::new()),
+    };
+    write_response(&mut client, status, "application/json", &response).await
+}
+
+/// Default name of a worker: the host name & process id
+pub fn worker_name() -> String {
+    let mut host = [0 as libc::c_char; 256];
+
+    // Safety: `host` is a buffer of the given length, the last byte stays nul
+    let res = unsafe { libc::gethostname(host.as_mut_ptr(), host.len() - 1) };
+    let host = match res {
+        // Safety: gethostname succeeded & `host` is nul terminated
+        0 => unsafe { CStr::from_ptr(host.as_ptr()) }
+            .to_string_lossy()
+            .to_string(),
+        _ => "worker".to_string(),
+    };
+    format!("{}-{}", host, std::process::id())
+}
+
+/// Scans work units of a coordinator with the local scanning engine
+pub struct Worker {
+    /// Name the coordinator knows the worker by
+    pub name: String,
+    pub coordinator: Url,
+    pub connector: Arc<Connector>,
+    pub resolver: Resolver,
+    pub concurrency: usize,
+    pub reverse_dns: bool,
+    /// Maximum amount of concurrent reverse dns lookups
+    pub dns_concurrency: usize,
+}
+
+impl Worker {
+    /// Send a request to the coordinator
+    async fn send(&self, path: &str, report: Option<Report>) -> Result<(u16, Vec<u8>), Error> {
+        let mut url = self.coordinator.clone();
+        url.path = format!("{}{}", url.path.trim_end_matches('/'), path);
+        let body = WorkerRequest {
+            worker: self.name.clone(),
+            report,
+        };
+        let body = serde_json::to_vec(&body).expect("Worker requests serialize");
+        send_json("POST", &url, &body)
+            .await
+            .map_err(|e| Error::Coordinator {
+                url: url.to_string(),
+                reason: e.to_string(),
+            })
+    }
+
+    /// Send a request to the coordinator, retrying failed exchanges with exponential backoff
+    ///
+    /// Once cancelled, only one more attempt is made.
+    async fn send_retrying(
+        &self,
+        path: &str,
+        report: Option<Report>,
+        cancel: &CancellationToken,
+    ) -> Result<(u16, Vec<u8>), Error> {
+        let mut delay = RETRY_DELAY;
+        for _ in 1..ATTEMPTS {
+            match self.send(path, report.clone()).await {
+                Ok(response) => return Ok(response),
+                Err(e) => eprintln!("{}, retrying in {:?}", e, delay),
+            }
+            tokio::select! {
+                _ = sleep(delay) => {}
+                _ = cancel.cancelled() => break,
+            }
+            delay *= 2;
+        }
+        self.send(path, report).await
+    }
+
+    /// Scan units until the coordinator has none left or until cancelled, returning the amount
+    pub async fn work(&self, cancel: &CancellationToken) -> Result<usize, Error> {
+        let mut scanned = 0;
+        while !cancel.is_cancelled() {
+            let (status, body) = self.send_retrying("/units", None, cancel).await?;
+            match status {
+                200 => {
+                    let unit: WorkUnit =
+                        serde_json::from_slice(&body).map_err(|e| Error::Coordinator {
+                            url: self.coordinator.to_string(),
+                            reason: format!("invalid work unit: {}", e),
+                        })?;
+                    if self.scan_unit(unit, cancel).await? {
+                        scanned += 1;
+                    }
+                }
+                204 => {
+                    tokio::select! {
+                        _ = sleep(POLL_INTERVAL) => {}
+                        _ = cancel.cancelled() => {}
+                    }
+                }
+                410 => break,
+                status => {
+                    return Err(Error::Coordinator {
+                        url: self.coordinator.to_string(),
+                        reason: format!("status {}", status),
+                    })
+                }
+            }
+        }
+        Ok(scanned)
+    }
+
+    /// Scan a unit while sending heartbeats, false when it was abandoned or interrupted
+    async fn scan_unit(&self, unit: WorkUnit, cancel: &CancellationToken) -> Result<bool, Error> {
+        let mut targets: Vec<Target> = Vec::new();
+        for target in unit.targets.iter() {
+            let invalid = |reason| Error::InvalidTarget {
+                target: target.address.clone(),
+                reason,
+            };
+            let address = match target.address.parse().map_err(invalid)? {
+                TargetSpec::Address(address) => address,
+                _ => return Err(invalid("not an address".to_string())),
+            };
+            let ports: Arc<[Port]> = target
+                .ports
+                .iter()
+                .map(|port| Port {
+                    service: intern_name(&port.service),
+                    number: port.number,
+                })
+                .collect();
+            // Targets usually share their ports
+            let ports = match targets.last() {
+                Some(last) if last.ports == ports => last.ports.clone(),
+                _ => ports,
+            };
+            let mut scan_target = Target::new(String::new(), address, ports);
+            scan_target.names = target.names.clone();
+            targets.push(scan_target);
+        }
+
+        // Abandon the unit once it was reassigned
+        let abandon = cancel.child_token();
+        let heartbeats = async {
+            let interval = Duration::from_millis(unit.lease_ms / 3).max(Duration::from_millis(10));
+            loop {
+                sleep(interval).await;
+                let path = format!("/units/{}/heartbeat", unit.id);
+                if let Ok((409, _)) = self.send(&path, None).await {
+                    eprintln!("Unit {} was reassigned, abandoning it", unit.id);
+                    abandon.cancel();
+                    return;
+                }
+            }
+        };
+        let hooks = ScanHooks {
+            cancel: abandon.clone(),
+            probes: None,
+            ..Default::default()
+        };
+        let scan = scan_targets(targets, self.concurrency, self.connector.clone(), hooks);
+        let mut results = tokio::select! {
+            results = scan => results,
+            _ = heartbeats => return Ok(false),
+        };
+
+        let complete = !cancel.is_cancelled();
+        if self.reverse_dns && complete {
+            self.resolver
+                .reverse_lookup_targets(
+                    results.iter_mut().filter_map(|res| res.as_mut().ok()),
+                    self.dns_concurrency,
+                )
+                .await;
+        }
+        let path = format!("/units/{}/results", unit.id);
+        self.send_retrying(&path, Some(Report::new(&results, complete)), cancel)
+            .await?;
+        Ok(complete)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dns::Family;
+
+    /// Worker scanning from localhost
+    fn worker(name: &str, coordinator: std::net::SocketAddr) -> Worker {
+        Worker {
+            name: name.to_string(),
+            coordinator: format!("http://{}", coordinator).parse().unwrap(),
+            connector: Arc::new(Connector::default()),
+            resolver: Resolver::new(Family::Any, true, None),
+            concurrency: 10,
+            reverse_dns: false,
+            dns_concurrency: 1,
+        }
+    }
+
+    /// Targets on loopback addresses with a listening port on the first
+    async fn targets() -> (Vec<Target>, TcpListener) {
+        let open = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ports: Arc<[Port]> = Arc::new([Port {
+            service: "unknown",
+            number: open.local_addr().unwrap().port(),
+        }]);
+        let targets = (1..=5)
+            .map(|host| {
+                let address = format!("127.0.0.{}:0", host).parse().unwrap();
+                Target::new(format!("host{}", host), address, ports.clone())
+            })
+            .collect();
+        (targets, open)
+    }
+
+    /// Check that two workers scan all units & the results are merged in order
+    #[tokio::test]
+    async fn workers_scan_all_units() {
+        let (targets, _open) = targets().await;
+        let units = work_units(&targets, 2);
+        assert_eq!(units.len(), 3);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let mut coordinator = Coordinator::new(units, Duration::from_secs(5));
+        coordinator.linger = Duration::from_millis(1500);
+        let coordinator = Arc::new(coordinator);
+        let cancel = CancellationToken::new();
+        let coordinating = tokio::spawn({
+            let cancel = cancel.clone();
+            async move { coordinate(listener, coordinator, &cancel).await }
+        });
+
+        let (first, second) = (worker("first", address), worker("second", address));
+        let (first, second) = tokio::join!(first.work(&cancel), second.work(&cancel));
+        assert_eq!(first.unwrap() + second.unwrap(), 3);
+
+        let report = coordinating.await.unwrap().unwrap();
+        assert!(report.complete);
+        let addresses: Vec<&str> = report.targets.iter().map(|t| t.address.as_str()).collect();
+        assert_eq!(
+            addresses,
+            vec![
+                "127.0.0.1",
+                "127.0.0.2",
+                "127.0.0.3",
+                "127.0.0.4",
+                "127.0.0.5"
+            ]
+        );
+        assert_eq!(report.targets[0].open_ports.len(), 1);
+    }
+
+    /// Check that the unit of a worker without heartbeats is reassigned & its late results ignored
+    #[tokio::test]
+    async fn reassign_dead_worker() {
+        let (targets, _open) = targets().await;
+        let coordinator = Coordinator::new(work_units(&targets, 5), Duration::from_millis(50));
+
+        let unit = coordinator.take("dead").unwrap().unwrap();
+        assert_eq!(coordinator.take("alive").unwrap(), None);
+
+        sleep(Duration::from_millis(60)).await;
+        let reassigned = coordinator.take("alive").unwrap().unwrap();
+        assert_eq!(reassigned.id, unit.id);
+        assert!(!coordinator.heartbeat(unit.id, "dead"));
+
+        let mut report = Report::new(&[], true);
+        report.failures.push(crate::output::FailureReport {
+            name: "alive".to_string(),
+            error: String::new(),
+        });
+        coordinator.finish(unit.id, "alive", report);
+        coordinator.finish(unit.id, "dead", Report::new(&[], true));
+        assert_eq!(coordinator.take("alive"), Err(()));
+        assert_eq!(coordinator.report().failures[0].name, "alive");
+    }
+
+    /// Check that targets with different ports keep their own ports in a unit
+    #[tokio::test]
+    async fn units_keep_target_ports() {
+        let open = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = |number| Port {
+            service: "unknown",
+            number,
+        };
+        let open_port = port(open.local_addr().unwrap().port());
+        let targets = vec![
+            Target::new(
+                "host".to_string(),
+                "127.0.0.2:0".parse().unwrap(),
+                Arc::new([port(1)]),
+            ),
+            Target::new(
+                "_xmpp-client._tcp.host".to_string(),
+                "127.0.0.1:0".parse().unwrap(),
+                Arc::new([port(1), open_port]),
+            ),
+        ];
+        let units = work_units(&targets, 2);
+        assert_eq!(units.len(), 1);
+        assert_eq!(units[0].targets[0].ports.len(), 1);
+        assert_eq!(units[0].targets[1].ports.len(), 2);
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let mut coordinator = Coordinator::new(units, Duration::from_secs(5));
+        coordinator.linger = Duration::from_millis(1500);
+        let cancel = CancellationToken::new();
+        let coordinating = tokio::spawn({
+            let cancel = cancel.clone();
+            async move { coordinate(listener, Arc::new(coordinator), &cancel).await }
+        });
+        assert_eq!(worker("only", address).work(&cancel).await.unwrap(), 1);
+
+        let report = coordinating.await.unwrap().unwrap();
+        let open_ports = |address: &str| {
+            let target = report.targets.iter().find(|t| t.address == address);
+            target.unwrap().open_ports.clone()
+        };
+        assert!(open_ports("127.0.0.2").is_empty());
+        assert_eq!(open_ports("127.0.0.1")[0].number, open_port.number);
+    }
+
+    /// Check that a worker keeps trying while the coordinator is not reachable yet
+    #[tokio::test]
+    async fn worker_retries_coordinator() {
+        let (targets, _open) = targets().await;
+        let address = TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let cancel = CancellationToken::new();
+        let working = tokio::spawn({
+            let cancel = cancel.clone();
+            async move { worker("early", address).work(&cancel).await }
+        });
+
+        sleep(Duration::from_millis(200)).await;
+        let listener = TcpListener::bind(address).await.unwrap();
+        let mut coordinator = Coordinator::new(work_units(&targets, 5), Duration::from_secs(5));
+        coordinator.linger = Duration::from_millis(1500);
+        let report = coordinate(listener, Arc::new(coordinator), &cancel)
+            .await
+            .unwrap();
+        assert!(report.complete);
+        assert_eq!(working.await.unwrap().unwrap(), 1);
+    }
+}
@@ -0,0 +1,780 @@
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::fs;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use futures::stream::{self, StreamExt};
+
+use socket2::SockAddr;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{lookup_host, TcpStream, UdpSocket};
+use tokio::time::{timeout, Duration};
+
+use crate::error::Error;
+use crate::port::Target;
+
+/// Address family of resolved addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Family {
+    Any,
+    V4,
+    V6,
+}
+
+impl Family {
+    /// Check if an address belongs to the family
+    fn contains(&self, address: &IpAddr) -> bool {
+        match self {
+            Family::Any => true,
+            Family::V4 => address.is_ipv4(),
+            Family::V6 => address.is_ipv6(),
+        }
+    }
+}
+
+/// Host name resolver with:
+/// - An address family preference
+/// - Whether to use all or only the first resolved address
+/// - An optional dns server, the system resolver is used otherwise
+/// - A cache of resolved host names
+#[derive(Debug)]
+pub struct Resolver {
+    family: Family,
+    all: bool,
+    server: Option<SocketAddr>,
+    cache: Mutex<HashMap<String, Result<Vec<IpAddr>, String>>>,
+}
+
+impl Resolver {
+    pub fn new(family: Family, all: bool, server: Option<SocketAddr>) -> Resolver {
+        Resolver {
+            family,
+            all,
+            server,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Forget resolved host names, so they are looked up again
+    pub fn clear_cache(&self) {
+        self.cache.lock().expect("Cache poisoned").clear();
+    }
+
+    /// Resolve a host name to its addresses, each name is only looked up once
+    pub async fn resolve(&self, host: &str) -> Result<Vec<IpAddr>, Error> {
+        let key = host.to_ascii_lowercase();
+        let cached = self
+            .cache
+            .lock()
+            .expect("Cache poisoned")
+            .get(&key)
+            .cloned();
+        let addresses = match cached {
+            Some(addresses) => addresses,
+            None => {
+                let addresses = self.lookup(host).await.map_err(|e| e.to_string());
+                self.cache
+                    .lock()
+                    .expect("Cache poisoned")
+                    .insert(key, addresses.clone());
+                addresses
+            }
+        };
+
+        let mut addresses: Vec<IpAddr> = addresses
+            .map_err(|message| Error::Dns {
+                host: host.to_string(),
+                source: io::Error::other(message),
+            })?
+            .into_iter()
+            .filter(|address| self.family.contains(address))
+            .collect();
+        if !self.all {
+            addresses.truncate(1);
+        }
+        match addresses.is_empty() {
+            true => Err(Error::NoAddresses {
+                host: host.to_string(),
+            }),
+            false => Ok(addresses),
+        }
+    }
+
+    /// Look up the addresses of a host name, without cache
+    async fn lookup(&self, host: &str) -> io::Result<Vec<IpAddr>> {
+        if let Ok(address) = host.parse() {
+            return Ok(vec![address]);
+        }
+        let server = match self.server {
+            Some(server) => server,
+            None => {
+                let addresses = lookup_host(format!("{}:0", host)).await?;
+                return Ok(addresses.map(|address| address.ip()).collect());
+            }
+        };
+
+        let mut addresses = Vec::new();
+        if self.family != Family::V6 {
+            for record in query(server, host, RecordType::A).await? {
+                if let Record::A(address) = record {
+                    addresses.push(IpAddr::V4(address));
+                }
+            }
+        }
+        if self.family != Family::V4 {
+            for record in query(server, host, RecordType::Aaaa).await? {
+                if let Record::Aaaa(address) = record {
+                    addresses.push(IpAddr::V6(address));
+                }
+            }
+        }
+        Ok(addresses)
+    }
+
+    /// Query records of a name from the dns server
+    ///
+    /// Without a configured server the first nameserver of the system is used.
+    pub async fn query(&self, name: &str, rtype: RecordType) -> io::Result<Vec<Record>> {
+        let server = match self.server {
+            Some(server) => server,
+            None => system_nameserver()?,
+        };
+        query(server, name, rtype).await
+    }
+
+    /// Reverse lookup the host names of responding targets, at most `concurrency` at a time
+    pub async fn reverse_lookup_targets<'a>(
+        &self,
+        targets: impl Iterator<Item = &'a mut Target>,
+        concurrency: usize,
+    ) {
+        let targets: Vec<&mut Target> = targets.filter(|target| target.is_responding()).collect();
+        // Collected, so the lookups can be awaited in spawned tasks
+        let lookups: Vec<_> = targets
+            .iter()
+            .map(|target| self.reverse_lookup(target.address.ip()))
+            .collect();
+        let hostnames: Vec<Option<String>> = stream::iter(lookups)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+
+        for (target, hostname) in targets.into_iter().zip(hostnames) {
+            target.hostname = hostname;
+        }
+    }
+
+    /// Reverse lookup the host name of an address
+    pub async fn reverse_lookup(&self, address: IpAddr) -> Option<String> {
+        match self.server {
+            Some(server) => {
+                let records = query(server, &reverse_name(address), RecordType::Ptr).await;
+                records.ok()?.into_iter().find_map(|record| match record {
+                    Record::Ptr(name) => Some(name),
+                    _ => None,
+                })
+            }
+            None => tokio::task::spawn_blocking(move || getnameinfo(address))
+                .await
+                .ok()
+                .flatten(),
+        }
+    }
+}
+
+/// Configuration file of the system resolver
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+
+/// First nameserver of the system resolver configuration
+fn system_nameserver() -> io::Result<SocketAddr> {
+    let config = fs::read_to_string(RESOLV_CONF)?;
+    config
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .find_map(|address| address.trim().parse::<IpAddr>().ok())
+        .map(|address| SocketAddr::new(address, 53))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no nameserver in {}", RESOLV_CONF),
+            )
+        })
+}
+
+/// Get the host name of an address via a PTR lookup of the system resolver
+fn getnameinfo(address: IpAddr) -> Option<String> {
+    let address = SockAddr::from(SocketAddr::new(address, 0));
+    let mut host = [0 as libc::c_char; libc::NI_MAXHOST as usize];
+
+    // Safety: `address` is a valid socket address of the given length and
+    // `host` is a buffer of the given length, which getnameinfo nul terminates
+    let res = unsafe {
+        libc::getnameinfo(
+            address.as_ptr(),
+            address.len(),
+            host.as_mut_ptr(),
+            host.len() as libc::socklen_t,
+            std::ptr::null_mut(),
+            0,
+            libc::NI_NAMEREQD,
+        )
+    };
+    if res != 0 {
+        return None;
+    }
+
+    // Safety: getnameinfo succeeded, so `host` holds a nul terminated string
+    let host = unsafe { CStr::from_ptr(host.as_ptr()) };
+    host.to_str().ok().map(str::to_string)
+}
+
+/// Name of the PTR record of an address
+fn reverse_name(address: IpAddr) -> String {
+    match address {
+        IpAddr::V4(address) => {
+            let [a, b, c, d] = address.octets();
+            format!("{}.{}.{}.{}.in-addr.arpa", d, c, b, a)
+        }
+        IpAddr::V6(address) => {
+            let mut name = String::new();
+            for byte in address.octets().iter().rev() {
+                name.push_str(&format!("{:x}.{:x}.", byte & 0xf, byte >> 4));
+            }
+            name + "ip6.arpa"
+        }
+    }
+}
+
+/// Dns record types used by the scanner
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordType {
+    A = 1,
+    Ptr = 12,
+    Aaaa = 28,
+    Srv = 33,
+}
+
+/// A record of a dns answer
+#[derive(Debug, Clone, PartialEq)]
+pub enum Record {
+    A(Ipv4Addr),
+    Aaaa(Ipv6Addr),
+    Ptr(String),
+    Srv {
+        priority: u16,
+        weight: u16,
+        port: u16,
+        target: String,
+    },
+    Other,
+}
+
+/// Timeout of a single dns request
+const QUERY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Amount of attempts of a dns request over udp
+const QUERY_ATTEMPTS: usize = 3;
+
+/// Rcode of a response for a name that does not exist
+const NXDOMAIN: u8 = 3;
+
+/// Query a dns server for the records of a name
+///
+/// Uses udp and retries over tcp when the response is truncated.
+/// A non-existing name results in a `NotFound` error.
+pub async fn query(server: SocketAddr, name: &str, rtype: RecordType) -> io::Result<Vec<Record>> {
+    let id = query_id();
+    let request = encode_query(id, name, rtype as u16)?;
+
+    let mut response = query_udp(server, &request).await?;
+    if response.truncated {
+        response = query_tcp(server, &request).await?;
+    }
+    if response.id != id {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "dns id mismatch",
+        ));
+    }
+    match response.rcode {
+        0 => Ok(response.answers),
+        NXDOMAIN => Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} does not exist", name),
+        )),
+        rcode => Err(io::Error::other(format!(
+            "dns server answered with rcode {}",
+            rcode
+        ))),
+    }
+}
+
+/// Send a request over udp, retrying on timeout
+async fn query_udp(server: SocketAddr, request: &[u8]) -> io::Result<Response> {
+    let local: SocketAddr = match server {
+        SocketAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+        SocketAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into(),
+    };
+    let socket = UdpSocket::bind(local).await?;
+    socket.connect(server).await?;
+
+    let mut buffer = [0; 4096];
+    for _ in 0..QUERY_ATTEMPTS {
+        socket.send(request).await?;
+        if let Ok(len) = timeout(QUERY_TIMEOUT, socket.recv(&mut buffer)).await {
+            return decode_response(&buffer[..len?]);
+        }
+    }
+    Err(io::Error::new(
+        io::ErrorKind::TimedOut,
+        format!("dns server {} did not respond", server),
+    ))
+}
+
+/// Send a request over tcp
+async fn query_tcp(server: SocketAddr, request: &[u8]) -> io::Result<Response> {
+    let exchange = async {
+        let mut stream = TcpStream::connect(server).await?;
+        stream
+            .write_all(&(request.len() as u16).to_be_bytes())
+            .await?;
+        stream.write_all(request).await?;
+
+        let len = stream.read_u16().await?;
+        let mut buffer = vec![0; len as usize];
+        stream.read_exact(&mut buffer).await?;
+        decode_response(&buffer)
+    };
+    timeout(QUERY_TIMEOUT, exchange)
+        .await
+        .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "dns over tcp timed out"))?
+}
+
+/// Generate a dns request id
+fn query_id() -> u16 {
+    static COUNTER: AtomicU16 = AtomicU16::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.subsec_nanos())
+        .unwrap_or(0);
+    (nanos as u16) ^ COUNTER.fetch_add(0x9e37, Ordering::Relaxed)
+}
+
+/// Encode a recursive query for one name
+fn encode_query(id: u16, name: &str, rtype: u16) -> io::Result<Vec<u8>> {
+    let mut packet = Vec::with_capacity(512);
+    packet.extend_from_slice(&id.to_be_bytes());
+    packet.extend_from_slice(&0x0100u16.to_be_bytes()); // Recursion desired
+    packet.extend_from_slice(&1u16.to_be_bytes()); // Questions
+    packet.extend_from_slice(&[0; 6]); // Answer, authority & additional records
+    encode_name(&mut packet, name)?;
+    packet.extend_from_slice(&rtype.to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes()); // Class IN
+    Ok(packet)
+}
+
+/// Encode a name as a sequence of labels
+fn encode_name(packet: &mut Vec<u8>, name: &str) -> io::Result<()> {
+    let name = name.trim_end_matches('.');
+    for label in name.split('.').filter(|_| !name.is_empty()) {
+        if label.is_empty() || label.len() > 63 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid dns name '{}'", name),
+            ));
+        }
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+    Ok(())
+}
+
+/// Decoded dns response
+#[derive(Debug)]
+struct Response {
+    id: u16,
+    rcode: u8,
+    truncated: bool,
+    answers: Vec<Record>,
+}
+
+/// Decode a dns response
+fn decode_response(packet: &[u8]) -> io::Result<Response> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    let header = packet
+        .get(..12)
+        .ok_or_else(|| invalid("dns header too short"))?;
+    let field = |index: usize| u16::from_be_bytes([header[index], header[index + 1]]);
+    if field(2) & 0x8000 == 0 {
+        return Err(invalid("dns packet is not a response"));
+    }
+
+    let mut reader = Reader { packet, pos: 12 };
+    for _ in 0..field(4) {
+        reader.name()?;
+        reader.take(4)?;
+    }
+    let mut answers = Vec::new();
+    for _ in 0..field(6) {
+        answers.push(reader.record()?);
+    }
+
+    Ok(Response {
+        id: field(0),
+        rcode: (field(2) & 0x000f) as u8,
+        truncated: field(2) & 0x0200 != 0,
+        answers,
+    })
+}
+
+/// Cursor in a dns packet
+struct Reader<'a> {
+    packet: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Take the next `len` bytes
+    fn take(&mut self, len: usize) -> io::Result<&'a [u8]> {
+        let bytes = self
+            .packet
+            .get(self.pos..self.pos + len)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "dns packet too short"))?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    /// Take the next big endian u16
+    fn u16(&mut self) -> io::Result<u16> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    /// Take the next, possibly compressed, name
+    fn name(&mut self) -> io::Result<String> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "invalid dns name");
+        let mut labels: Vec<String> = Vec::new();
+        let mut pos = self.pos;
+        let mut end = None;
+        for _ in 0..128 {
+            let len = *self.packet.get(pos).ok_or_else(invalid)? as usize;
+            match len {
+                0 => {
+                    self.pos = end.unwrap_or(pos + 1);
+                    return Ok(labels.join("."));
+                }
+                len if len & 0xc0 == 0xc0 => {
+                    let low = *self.packet.get(pos + 1).ok_or_else(invalid)? as usize;
+                    end.get_or_insert(pos + 2);
+                    pos = ((len & 0x3f) << 8) | low;
+                }
+                len => {
+                    let label = self
+                        .packet
+                        .get(pos + 1..pos + 1 + len)
+                        .ok_or_else(invalid)?;
+                    labels.push(String::from_utf8_lossy(label).into_owned());
+                    pos += 1 + len;
+                }
+            }
+        }
+        Err(invalid())
+    }
+
+    /// Take the next resource record
+    fn record(&mut self) -> io::Result<Record> {
+        self.name()?;
+        let rtype = self.u16()?;
+        self.take(6)?; // Class & ttl
+        let len = self.u16()? as usize;
+        let start = self.pos;
+        let data = self.take(len)?;
+
+        let record = match rtype {
+            1 if len == 4 => Record::A(Ipv4Addr::new(data[0], data[1], data[2], data[3])),
+            28 if len == 16 => {
+                let octets: [u8; 16] = data.try_into().expect("Length checked");
+                Record::Aaaa(Ipv6Addr::from(octets))
+            }
+            12 => {
+                let mut reader = Reader {
+                    packet: self.packet,
+                    pos: start,
+                };
+                Record::Ptr(reader.name()?)
+            }
+            33 if len >= 7 => {
+                let mut reader = Reader {
+                    packet: self.packet,
+                    pos: start,
+                };
+                Record::Srv {
+                    priority: reader.u16()?,
+                    weight: reader.u16()?,
+                    port: reader.u16()?,
+                    target: reader.name()?,
+                }
+            }
+            _ => Record::Other,
+        };
+        Ok(record)
+    }
+}
+
+/// Local dns server answering from a fixed set of records
+#[cfg(test)]
+pub mod stand_in {
+    use super::*;
+
+    /// Spawn a server on localhost for `(name, record)` pairs
+    ///
+    /// A `*.domain` name answers for every name below the domain without records
+    /// of its own, names that match no record get a NXDOMAIN response.
+    pub async fn spawn(records: Vec<(&'static str, Record)>) -> SocketAddr {
+        let socket = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let address = socket.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buffer = [0; 512];
+            loop {
+                let (len, peer) = socket.recv_from(&mut buffer).await.unwrap();
+                let response = respond(&buffer[..len], &records);
+                socket.send_to(&response, peer).await.unwrap();
+            }
+        });
+        address
+    }
+
+    /// Build the response to a query
+    fn respond(query: &[u8], records: &[(&'static str, Record)]) -> Vec<u8> {
+        let mut reader = Reader {
+            packet: query,
+            pos: 12,
+        };
+        let name = reader.name().unwrap();
+        let rtype = reader.u16().unwrap();
+        let question = &query[12..reader.pos + 2];
+
+        let exact = records.iter().any(|(n, _)| n.eq_ignore_ascii_case(&name));
+        let matching: Vec<&Record> = records
+            .iter()
+            .filter(|(n, _)| match exact {
+                true => n.eq_ignore_ascii_case(&name),
+                false => matches_wildcard(n, &name),
+            })
+            .map(|(_, record)| record)
+            .collect();
+        let known = !matching.is_empty();
+        let answers: Vec<&Record> = matching
+            .into_iter()
+            .filter(|record| record_type(record) == rtype)
+            .collect();
+
+        let mut packet = Vec::new();
+        packet.extend_from_slice(&query[..2]);
+        let rcode = if known { 0 } else { NXDOMAIN as u16 };
+        packet.extend_from_slice(&(0x8180 | rcode).to_be_bytes());
+        packet.extend_from_slice(&1u16.to_be_bytes());
+        packet.extend_from_slice(&(answers.len() as u16).to_be_bytes());
+        packet.extend_from_slice(&[0; 4]);
+        packet.extend_from_slice(question);
+        for record in answers {
+            packet.extend_from_slice(&0xc00cu16.to_be_bytes()); // Pointer to question name
+            packet.extend_from_slice(&rtype.to_be_bytes());
+            packet.extend_from_slice(&1u16.to_be_bytes());
+            packet.extend_from_slice(&60u32.to_be_bytes());
+            let data = record_data(record);
+            packet.extend_from_slice(&(data.len() as u16).to_be_bytes());
+            packet.extend_from_slice(&data);
+        }
+        packet
+    }
+
+    /// Check if `*.domain` matches a name below the domain
+    fn matches_wildcard(pattern: &str, name: &str) -> bool {
+        match pattern.strip_prefix("*.") {
+            Some(domain) => name
+                .to_ascii_lowercase()
+                .ends_with(&format!(".{}", domain.to_ascii_lowercase())),
+            None => false,
+        }
+    }
+
+    /// Type number of a record
+    fn record_type(record: &Record) -> u16 {
+        match record {
+            Record::A(_) => RecordType::A as u16,
+            Record::Aaaa(_) => RecordType::Aaaa as u16,
+            Record::Ptr(_) => RecordType::Ptr as u16,
+            Record::Srv { .. } => RecordType::Srv as u16,
+            Record::Other => 0,
+        }
+    }
+
+    /// Wire format data of a record
+    fn record_data(record: &Record) -> Vec<u8> {
+        let mut data = Vec::new();
+        match record {
+            Record::A(address) => data.extend_from_slice(&address.octets()),
+            Record::Aaaa(address) => data.extend_from_slice(&address.octets()),
+            Record::Ptr(name) => encode_name(&mut data, name).unwrap(),
+            Record::Srv {
+                priority,
+                weight,
+                port,
+                target,
+            } => {
+                data.extend_from_slice(&priority.to_be_bytes());
+                data.extend_from_slice(&weight.to_be_bytes());
+                data.extend_from_slice(&port.to_be_bytes());
+                encode_name(&mut data, target).unwrap();
+            }
+            Record::Other => (),
+        }
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::{Port, PortState};
+    use std::sync::Arc;
+
+    /// Check that the loopback address resolves to a name
+    #[tokio::test]
+    async fn reverse_lookup_loopback() {
+        let resolver = Resolver::new(Family::Any, true, None);
+        let hostname = resolver.reverse_lookup("127.0.0.1".parse().unwrap()).await;
+
+        assert!(hostname.is_some());
+    }
+
+    /// Check address family filtering & first address selection with a custom resolver
+    #[tokio::test]
+    async fn resolve_with_server() {
+        let server = stand_in::spawn(vec![
+            ("web.example.test", Record::A(Ipv4Addr::new(192, 0, 2, 1))),
+            ("web.example.test", Record::A(Ipv4Addr::new(192, 0, 2, 2))),
+            (
+                "web.example.test",
+                Record::Aaaa("2001:db8::1".parse().unwrap()),
+            ),
+        ])
+        .await;
+
+        let all = Resolver::new(Family::Any, true, Some(server));
+        let addresses = all.resolve("web.example.test").await.unwrap();
+        assert_eq!(addresses.len(), 3);
+
+        let v6 = Resolver::new(Family::V6, true, Some(server));
+        let addresses = v6.resolve("web.example.test").await.unwrap();
+        assert_eq!(addresses, vec!["2001:db8::1".parse::<IpAddr>().unwrap()]);
+
+        let first = Resolver::new(Family::V4, false, Some(server));
+        let addresses = first.resolve("web.example.test").await.unwrap();
+        assert_eq!(addresses, vec![IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))]);
+    }
+
+    /// Check that a non-existing name & a name without addresses fail
+    #[tokio::test]
+    async fn resolve_failures() {
+        let server = stand_in::spawn(vec![(
+            "v6only.example.test",
+            Record::Aaaa("2001:db8::1".parse().unwrap()),
+        )])
+        .await;
+        let resolver = Resolver::new(Family::V4, true, Some(server));
+
+        let missing = resolver.resolve("missing.example.test").await;
+        assert!(matches!(missing, Err(Error::Dns { .. })));
+        let v6only = resolver.resolve("v6only.example.test").await;
+        assert!(matches!(v6only, Err(Error::NoAddresses { .. })));
+    }
+
+    /// Check that repeated host names are served from the cache
+    #[tokio::test]
+    async fn resolve_cached() {
+        let server = stand_in::spawn(vec![(
+            "cached.example.test",
+            Record::A(Ipv4Addr::new(192, 0, 2, 3)),
+        )])
+        .await;
+        let resolver = Resolver::new(Family::Any, true, Some(server));
+        resolver.resolve("cached.example.test").await.unwrap();
+
+        let cache = resolver.cache.lock().unwrap();
+        assert_eq!(cache.len(), 1);
+        assert!(cache.contains_key("cached.example.test"));
+    }
+
+    /// Check reverse lookups through a custom resolver
+    #[tokio::test]
+    async fn reverse_lookup_with_server() {
+        let server = stand_in::spawn(vec![(
+            "1.2.0.192.in-addr.arpa",
+            Record::Ptr("host.example.test".to_string()),
+        )])
+        .await;
+        let resolver = Resolver::new(Family::Any, true, Some(server));
+        let hostname = resolver.reverse_lookup("192.0.2.1".parse().unwrap()).await;
+
+        assert_eq!(hostname.as_deref(), Some("host.example.test"));
+    }
+
+    /// Check that bounded reverse lookups of targets keep each target's name
+    #[tokio::test]
+    async fn reverse_lookup_targets_bounded() {
+        let server = stand_in::spawn(
+            [
+                ("1.2.0.192.in-addr.arpa", "one.example.test"),
+                ("2.2.0.192.in-addr.arpa", "two.example.test"),
+                ("3.2.0.192.in-addr.arpa", "three.example.test"),
+            ]
+            .into_iter()
+            .map(|(name, host)| (name, Record::Ptr(host.to_string())))
+            .collect(),
+        )
+        .await;
+        let resolver = Resolver::new(Family::Any, true, Some(server));
+        let ports: Arc<[Port]> = Arc::new([Port {
+            service: "ssh",
+            number: 22,
+        }]);
+        let mut targets: Vec<Target> = (1..=3)
+            .map(|host| {
+                let address = SocketAddr::from(([192, 0, 2, host], 0));
+                let mut target = Target::new(address.ip().to_string(), address, ports.clone());
+                target.states.set(0, PortState::Closed);
+                target
+            })
+            .collect();
+
+        resolver.reverse_lookup_targets(targets.iter_mut(), 2).await;
+        let hostnames: Vec<_> = targets.iter().map(|t| t.hostname.as_deref()).collect();
+        assert_eq!(
+            hostnames,
+            vec![
+                Some("one.example.test"),
+                Some("two.example.test"),
+                Some("three.example.test")
+            ]
+        );
+    }
+
+    /// Check the PTR name of an ipv6 address
+    #[test]
+    fn reverse_name_v6() {
+        let name = reverse_name("2001:db8::1".parse().unwrap());
+
+        assert_eq!(
+            name,
+            "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.ip6.arpa"
+        );
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use crate::address::display_ip;
+use crate::services_file::ParseError;
+
+/// Errors of the port scanner
+#[derive(Debug)]
+pub enum Error {
+    /// Reading a services file failed
+    ServicesFile { path: PathBuf, source: io::Error },
+    /// A services file contains an invalid line
+    ServicesParse { path: PathBuf, source: ParseError },
+    /// Reading a wordlist failed
+    Wordlist { path: PathBuf, source: io::Error },
+    /// Writing results failed
+    Output { path: PathBuf, source: io::Error },
+    /// Reading saved results failed
+    Results { path: PathBuf, source: io::Error },
+    /// Saved results are of an interrupted scan or have unscanned ports
+    IncompleteResults { path: PathBuf },
+    /// The results database cannot be opened, written or queried
+    Database { path: PathBuf, reason: String },
+    /// A configuration file cannot be read or is invalid
+    Config { path: PathBuf, reason: String },
+    /// The selected profile is in no configuration file
+    UnknownProfile { name: String },
+    /// Options of a profile conflict with each other or with given options
+    InvalidProfile { name: String, reason: String },
+    /// The coordinator of distributed scans failed or cannot be reached
+    Coordinator { url: String, reason: String },
+    /// A policy file cannot be read or is invalid
+    Policy { path: PathBuf, reason: String },
+    /// Reporting a change to an alert sink failed
+    Alert { sink: String, reason: String },
+    /// Reading or writing a checkpoint file failed
+    Checkpoint { path: PathBuf, source: io::Error },
+    /// Probe sockets cannot be bound to the configured source
+    Bind { local: String, source: io::Error },
+    /// A target is no valid host name, address or network
+    InvalidTarget { target: String, reason: String },
+    /// Resolving a host name failed
+    Dns { host: String, source: io::Error },
+    /// A host name resolved to no addresses
+    NoAddresses { host: String },
+    /// The network of a target is unreachable
+    Unroutable {
+        address: SocketAddr,
+        source: io::Error,
+    },
+    /// A proxy failed or refused to tunnel a probe
+    Proxy { proxy: String, reason: String },
+    /// The ssh jump host failed or refused forwarding
+    Ssh { destination: String, reason: String },
+    /// Connecting to a port failed for another reason than a refusal or timeout
+    Connect {
+        address: SocketAddr,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::ServicesFile { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            Error::ServicesParse { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Wordlist { path, source } => {
+                write!(f, "failed to read {}: {}", path.display(), source)
+            }
+            Error::Output { path, source } => {
+                write!(f, "failed to write {}: {}", path.display(), source)
+            }
+            Error::Results { path, source } => {
+                write!(f, "failed to read results {}: {}", path.display(), source)
+            }
+            Error::IncompleteResults { path } => write!(
+                f,
+                "results {} are incomplete, unscanned ports cannot be compared",
+                path.display()
+            ),
+            Error::Database { path, reason } => {
+                write!(f, "database {}: {}", path.display(), reason)
+            }
+            Error::Config { path, reason } => write!(f, "{}: {}", path.display(), reason),
+            Error::UnknownProfile { name } => write!(f, "unknown profile '{}'", name),
+            Error::InvalidProfile { name, reason } => write!(f, "profile '{}': {}", name, reason),
+            Error::Coordinator { url, reason } => write!(f, "coordinator {}: {}", url, reason),
+            Error::Policy { path, reason } => write!(f, "{}: {}", path.display(), reason),
+            Error::Alert { sink, reason } => write!(f, "alert to {}: {}", sink, reason),
+            Error::Checkpoint { path, source } => {
+                write!(f, "checkpoint {}: {}", path.display(), source)
+            }
+            Error::Bind { local, source } => write!(f, "cannot bind to {}: {}", local, source),
+            Error::InvalidTarget { target, reason } => {
+                write!(f, "invalid target {}: {}", target, reason)
+            }
+            Error::Dns { host, source } => write!(f, "dns lookup of {} failed: {}", host, source),
+            Error::NoAddresses { host } => write!(f, "{} resolved to no addresses", host),
+            Error::Unroutable { address, source } => {
+                write!(f, "{} is unroutable: {}", display_ip(address), source)
+            }
+            Error::Proxy { proxy, reason } => write!(f, "proxy {}: {}", proxy, reason),
+            Error::Ssh {
+                destination,
+                reason,
+            } => write!(f, "ssh jump host {}: {}", destination, reason),
+            Error::Connect { address, source } => {
+                write!(f, "failed to connect to {}: {}", address, source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::ServicesFile { source, .. }
+            | Error::Wordlist { source, .. }
+            | Error::Output { source, .. }
+            | Error::Checkpoint { source, .. }
+            | Error::Results { source, .. }
+            | Error::Bind { source, .. }
+            | Error::Dns { source, .. }
+            | Error::Unroutable { source, .. }
+            | Error::Connect { source, .. } => Some(source),
+            Error::ServicesParse { source, .. } => Some(source),
+            Error::InvalidTarget { .. }
+            | Error::NoAddresses { .. }
+            | Error::Proxy { .. }
+            | Error::Ssh { .. }
+            | Error::Alert { .. }
+            | Error::Policy { .. }
+            | Error::Database { .. }
+            | Error::IncompleteResults { .. }
+            | Error::Config { .. }
+            | Error::Coordinator { .. }
+            | Error::UnknownProfile { .. }
+            | Error::InvalidProfile { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,287 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::time::{timeout, Duration};
+
+/// Time to wait for an HTTP request to complete
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Maximum size of a received request head
+const MAX_HEAD: usize = 16 * 1024;
+
+/// Maximum size of a received request body
+const MAX_BODY: usize = 1024 * 1024;
+
+/// A received HTTP request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub body: Vec<u8>,
+}
+
+/// An `http://host[:port][/path]` url
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Url {
+    pub host: String,
+    pub port: u16,
+    pub path: String,
+}
+
+impl TryFrom<String> for Url {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for Url {
+    type Err = String;
+
+    /// Parse an `http://` url, the port defaults to 80 and the path to `/`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rest = s
+            .strip_prefix("http://")
+            .ok_or_else(|| format!("expected http:// url, got '{}'", s))?;
+        let (authority, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) if !host.contains(':') || host.ends_with(']') => {
+                let port = port
+                    .parse()
+                    .map_err(|_| format!("invalid url port '{}'", port))?;
+                (host, port)
+            }
+            _ => (authority, 80),
+        };
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if host.is_empty() {
+            return Err(format!("missing host in url '{}'", s));
+        }
+        Ok(Url {
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Url {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.host.contains(':') {
+            write!(f, "http://[{}]:{}{}", self.host, self.port, self.path)
+        } else {
+            write!(f, "http://{}:{}{}", self.host, self.port, self.path)
+        }
+    }
+}
+
+/// POST a json body to `url`, returning the response status
+pub async fn post_json(url: &Url, body: &[u8]) -> io::Result<u16> {
+    send_json("POST", url, body).await.map(|(status, _)| status)
+}
+
+/// Send a request with a json body to `url`, returning the response status & body
+pub async fn send_json(method: &str, url: &Url, body: &[u8]) -> io::Result<(u16, Vec<u8>)> {
+    let request = async {
+        let mut stream = TcpStream::connect((url.host.as_str(), url.port)).await?;
+        let head = format!(
+            "{} {} HTTP/1.1\r\nHost: {}\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n",
+            method,
+            url.path,
+            url.host,
+            body.len()
+        );
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(body).await?;
+
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response).await?;
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "invalid http response");
+        let head_end = response
+            .windows(4)
+            .position(|w| w == b"\r\n\r\n")
+            .ok_or_else(invalid)?;
+        let status = String::from_utf8_lossy(&response[..head_end])
+            .split_whitespace()
+            .nth(1)
+            .and_then(|status| status.parse().ok())
+            .ok_or_else(invalid)?;
+        Ok((status, response.split_off(head_end + 4)))
+    };
+    timeout(REQUEST_TIMEOUT, request)
+        .await
+        .unwrap_or_else(|_| Err(io::ErrorKind::TimedOut.into()))
+}
+
+/// Read a request with a `Content-Length` body, if any
+pub async fn read_request(stream: &mut TcpStream) -> io::Result<Request> {
+    let invalid = |reason: &str| io::Error::new(io::ErrorKind::InvalidData, reason.to_string());
+    let request = async {
+        let mut received = Vec::new();
+        let mut buffer = [0; 4096];
+        let head_end = loop {
+            if let Some(index) = received.windows(4).position(|w| w == b"\r\n\r\n") {
+                break index;
+            }
+            if received.len() > MAX_HEAD {
+                return Err(invalid("request head too large"));
+            }
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            received.extend_from_slice(&buffer[..read]);
+        };
+
+        let head = String::from_utf8_lossy(&received[..head_end]).to_string();
+        let mut lines = head.lines();
+        let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+        let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+            return Err(invalid("invalid request line"));
+        };
+        let length = lines
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+            .map(|(_, length)| length.trim().parse::<usize>())
+            .transpose()
+            .map_err(|_| invalid("invalid content length"))?
+            .unwrap_or(0);
+        if length > MAX_BODY {
+            return Err(invalid("request body too large"));
+        }
+
+        let mut body = received.split_off(head_end + 4);
+        while body.len() < length {
+            let read = stream.read(&mut buffer).await?;
+            if read == 0 {
+                return Err(io::ErrorKind::UnexpectedEof.into());
+            }
+            body.extend_from_slice(&buffer[..read]);
+        }
+        body.truncate(length);
+        Ok(Request {
+            method: method.to_string(),
+            path: path.to_string(),
+            body,
+        })
+    };
+    timeout(REQUEST_TIMEOUT, request)
+        .await
+        .unwrap_or_else(|_| Err(io::ErrorKind::TimedOut.into()))
+}
+
+/// Reason phrase of a status code
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        202 => "Accepted",
+        204 => "No Content",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+/// Write a response with a complete body & close the connection
+pub async fn write_response(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &[u8],
+) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        status,
+        reason(status),
+        content_type,
+        body.len()
+    );
+    stream.write_all(head.as_bytes()).await?;
+    stream.write_all(body).await?;
+    stream.shutdown().await
+}
+
+/// Write the head of a response whose body lasts until the connection is closed
+pub async fn write_stream_head(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        status,
+        reason(status),
+        content_type
+    );
+    stream.write_all(head.as_bytes()).await
+}
+
+#[cfg(test)]
+pub mod stand_in {
+    use super::*;
+    use std::net::SocketAddr;
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    /// Spawn a webhook receiver on localhost, forwarding the body of every request
+    pub async fn spawn_webhook() -> (SocketAddr, mpsc::UnboundedReceiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (bodies_tx, bodies_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            loop {
+                let (mut client, _) = listener.accept().await.unwrap();
+                let bodies_tx = bodies_tx.clone();
+                tokio::spawn(async move {
+                    if let Ok(request) = read_request(&mut client).await {
+                        let _ = bodies_tx.send(String::from_utf8_lossy(&request.body).to_string());
+                        let _ = write_response(&mut client, 204, "text/plain", b"").await;
+                    }
+                });
+            }
+        });
+        (address, bodies_rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check parsing of urls with default & explicit ports
+    #[test]
+    fn parse_url() {
+        let url: Url = "http://hooks.example.com".parse().unwrap();
+        assert_eq!(url.to_string(), "http://hooks.example.com:80/");
+
+        let url: Url = "http://[::1]:8080/alerts?team=net".parse().unwrap();
+        assert_eq!((url.host.as_str(), url.port), ("::1", 8080));
+        assert_eq!(url.path, "/alerts?team=net");
+
+        assert!("https://hooks.example.com".parse::<Url>().is_err());
+    }
+
+    /// Check that a json body is posted
+    #[tokio::test]
+    async fn post_to_stand_in() {
+        let (address, mut bodies) = stand_in::spawn_webhook().await;
+        let url: Url = format!("http://{}/hook", address).parse().unwrap();
+
+        let status = post_json(&url, b"{\"ok\":true}").await.unwrap();
+        assert_eq!(status, 204);
+        assert_eq!(bodies.recv().await.unwrap(), "{\"ok\":true}");
+    }
+}
@@ -0,0 +1,34 @@
+use tokio_util::sync::CancellationToken;
+
+/// Exit code of a run that was interrupted (128 + SIGINT)
+pub const INTERRUPTED: u8 = 130;
+
+/// Cancel `token` on the first SIGINT or SIGTERM, exit on the second
+pub fn cancel_on_interrupt(token: CancellationToken) {
+    tokio::spawn(async move {
+        interrupted().await;
+        eprintln!("Interrupted, finishing with partial results (interrupt again to exit)");
+        token.cancel();
+
+        interrupted().await;
+        std::process::exit(INTERRUPTED.into());
+    });
+}
+
+/// Wait for SIGINT or SIGTERM
+#[cfg(unix)]
+async fn interrupted() {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut terminate = signal(SignalKind::terminate()).expect("Cannot listen for SIGTERM");
+    tokio::select! {
+        _ = tokio::signal::ctrl_c() => {}
+        _ = terminate.recv() => {}
+    }
+}
+
+/// Wait for Ctrl-C
+#[cfg(not(unix))]
+async fn interrupted() {
+    let _ = tokio::signal::ctrl_c().await;
+}
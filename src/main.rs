@@ -1,20 +1,275 @@
-use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex};
 
-use tokio::net::lookup_host;
+use clap::{ArgMatches, Args, CommandFactory, FromArgMatches, Parser, Subcommand};
+use futures::future::join_all;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::time::Duration;
+use tokio_util::sync::CancellationToken;
 
-use clap::{command, Parser};
+mod address;
+
+mod checkpoint;
+use checkpoint::{spawn_checkpointer, Checkpoint};
+
+mod connect;
+use connect::Connector;
+
+mod discovery;
+use discovery::{discover_targets, write_hosts, Discovery, DiscoveryOptions};
+
+mod database;
+use database::{parse_age, unix_time, write_sightings, Database, Query, StateFilter};
+
+mod diff;
+use diff::{diff_targets, write_diff};
+
+mod distributed;
+use distributed::{coordinate, work_units, worker_name, Coordinator, Worker};
+
+mod dns;
+use dns::{Family, Resolver};
+
+mod error;
+use error::Error;
+
+mod http;
+use http::Url;
+
+mod interrupt;
+use interrupt::{cancel_on_interrupt, INTERRUPTED};
 
 mod common_ports;
-use common_ports::get_common_ports;
+use common_ports::{write_services, Protocol, ServiceRegistry};
+
+mod policy;
+use policy::{exit_code, Policy, Violation};
 
 mod port;
-use port::{scan_targets, Port, Target};
+use port::{dedup_targets, scan_targets, Port, ScanHooks, Target, TargetFailure};
+
+mod profile;
+use profile::{config_paths, Config};
+
+mod proxy;
+use proxy::Proxy;
+
+mod serve;
+use serve::{serve, Limits, Server};
+
+mod services_file;
+
+mod shard;
+use shard::Shard;
+
+mod ssh;
+use ssh::SshJump;
+
+mod monitor;
+use monitor::{monitor, Alerts};
+
+mod output;
+use output::{write_report, write_results, Format, Report};
 
 /// Command line arguments
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
-struct Args {
-    /// Address to scan
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    #[command(flatten)]
+    global: GlobalArgs,
+}
+
+/// Options shared by every command
+#[derive(Args, Debug)]
+struct GlobalArgs {
+    /// Additional services file in nmap-services format (overrides embedded entries)
+    #[clap(long, global = true)]
+    services_file: Vec<PathBuf>,
+
+    /// Format of the output
+    #[clap(long, value_enum, default_value_t = Format::Text, global = true)]
+    format: Format,
+
+    /// File to write the output to instead of stdout
+    #[clap(short, long, global = true)]
+    output: Option<PathBuf>,
+
+    /// Profile of options from the configuration files, overridden by given options
+    #[clap(long, global = true)]
+    profile: Option<String>,
+
+    /// Configuration file with profiles, besides the system & user files
+    #[clap(long, global = true)]
+    config: Option<PathBuf>,
+}
+
+/// Commands
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Scan the tcp ports of hosts
+    Scan(Box<ScanArgs>),
+
+    /// Resolve hosts & find more with SRV records and subdomains, without scanning
+    Discover {
+        /// Host name, ipv4 or ipv6 address (with optional %zone) or small network in CIDR notation
+        #[clap(required = true)]
+        address: Vec<String>,
+
+        #[command(flatten)]
+        dns: DnsArgs,
+    },
+
+    /// Compare two results saved with --format json
+    Diff {
+        /// Results of the earlier scan
+        old: PathBuf,
+
+        /// Results of the later scan
+        new: PathBuf,
+    },
+
+    /// Show results saved with --format json, optionally checked against a policy
+    Report {
+        /// Saved results, several are merged, e.g. the results of every --shard
+        #[clap(required = true)]
+        results: Vec<PathBuf>,
+
+        /// Policy file to check the results against
+        #[clap(long)]
+        policy: Option<PathBuf>,
+    },
+
+    /// List ports stored in a results database
+    Query {
+        /// Database file the results were stored in with --database
+        database: PathBuf,
+
+        /// Only this port
+        #[clap(long)]
+        port: Option<u16>,
+
+        /// Only the host with this address or name
+        #[clap(long)]
+        host: Option<String>,
+
+        /// Only ports in this state
+        #[clap(long, value_enum, default_value_t = StateFilter::Open)]
+        state: StateFilter,
+
+        /// Only runs of this age or newer, e.g. 12h or 7d
+        #[clap(long, value_parser = parse_age)]
+        since: Option<u64>,
+
+        /// Only the first run each port of a host was seen in
+        #[clap(long)]
+        first_seen: bool,
+    },
+
+    /// Serve an http api to submit scans, poll their progress & fetch the results
+    Serve {
+        /// Address to listen on
+        #[clap(long, default_value = "127.0.0.1:8080")]
+        listen: SocketAddr,
+
+        /// Maximum amount of jobs running at the same time
+        #[clap(long, default_value_t = 2, value_parser = parse_positive)]
+        max_jobs: usize,
+
+        /// Maximum amount of jobs queued or running, more are refused
+        #[clap(long, default_value_t = 16)]
+        max_queued: usize,
+
+        /// Maximum amount of ports probed at the same time per job
+        #[clap(long, default_value_t = 500, value_parser = parse_positive)]
+        concurrency: usize,
+
+        /// Maximum amount of addresses per job, networks count with all their addresses
+        #[clap(long, default_value_t = 65536, value_parser = parse_positive)]
+        max_targets: usize,
+
+        /// Amount of finished jobs to keep the results of, older ones are removed
+        #[clap(long, default_value_t = 100)]
+        keep_finished: usize,
+
+        #[command(flatten)]
+        dns: DnsArgs,
+    },
+
+    /// Hand out the targets to scan to workers & merge their results
+    Coordinate {
+        /// Host name, ipv4 or ipv6 address (with optional %zone) or small network in CIDR notation
+        #[clap(required = true)]
+        address: Vec<String>,
+
+        /// Ports to scan
+        #[clap(short, long)]
+        port: Option<Vec<u16>>,
+
+        /// Amount of common ports to scan, by open-frequency
+        #[clap(short, long)]
+        common: Option<usize>,
+
+        /// Address to listen for workers on
+        #[clap(long, default_value = "127.0.0.1:8090")]
+        listen: SocketAddr,
+
+        /// Amount of targets per work unit
+        #[clap(long, default_value_t = 16, value_parser = parse_positive)]
+        unit_size: usize,
+
+        /// Seconds without heartbeat after which the unit of a worker is reassigned
+        #[clap(long, default_value_t = 30)]
+        lease: u64,
+
+        #[command(flatten)]
+        dns: DnsArgs,
+    },
+
+    /// Scan work units of a coordinator until all targets are scanned
+    Work {
+        /// Url of the coordinator, e.g. http://10.0.0.1:8090
+        coordinator: Url,
+
+        /// Name of the worker for the coordinator, the host name & process id by default
+        #[clap(long)]
+        name: Option<String>,
+
+        /// Maximum amount of ports probed at the same time
+        #[clap(long, default_value_t = 500, value_parser = parse_positive)]
+        concurrency: usize,
+
+        /// Skip reverse dns lookups of responding hosts
+        #[clap(long)]
+        no_reverse_dns: bool,
+
+        /// Maximum amount of concurrent reverse dns lookups
+        #[clap(long, default_value_t = 20, value_parser = parse_positive)]
+        dns_concurrency: usize,
+    },
+
+    /// Search known services by port number or name, all services without a query
+    Services {
+        /// Port number or part of a service name
+        query: Option<String>,
+
+        /// Only services of this protocol: tcp, udp or sctp
+        #[clap(long)]
+        protocol: Option<Protocol>,
+    },
+}
+
+/// Options of a scan
+#[derive(Args, Debug)]
+struct ScanArgs {
+    /// Host name, ipv4 or ipv6 address (with optional %zone) or small network in CIDR notation
     #[clap(required = true)]
     address: Vec<String>,
 
@@ -22,68 +277,806 @@ struct Args {
     #[clap(short, long)]
     port: Option<Vec<u16>>,
 
-    /// Amount of common ports to scan (maximum 5000)
+    /// Amount of common ports to scan, by open-frequency
     #[clap(short, long)]
     common: Option<usize>,
+
+    /// Maximum amount of ports probed at the same time
+    #[clap(long, default_value_t = 500, value_parser = parse_positive)]
+    concurrency: usize,
+
+    /// Local address to send probes from
+    #[clap(long)]
+    source_ip: Option<IpAddr>,
+
+    /// Local port to send probes from, e.g. 53 or 20 to pass source port filters
+    #[clap(long, conflicts_with_all = ["proxy", "ssh_jump"])]
+    source_port: Option<u16>,
+
+    /// Network interface to send probes from
+    #[clap(long)]
+    interface: Option<String>,
+
+    /// Proxy to tunnel probes through: socks5://[user:pass@]host[:port] or
+    /// http://[user:pass@]host[:port], repeat to chain proxies in order
+    #[clap(long)]
+    proxy: Vec<Proxy>,
+
+    /// Ssh jump host to tunnel probes through: [user@]host[:port], connected
+    /// to with the OpenSSH client & its configuration
+    #[clap(long, conflicts_with = "proxy")]
+    ssh_jump: Option<String>,
+
+    /// Ssh client program for --ssh-jump
+    #[clap(long, default_value = "ssh")]
+    ssh_command: PathBuf,
+
+    #[command(flatten)]
+    dns: DnsArgs,
+
+    /// Only scan this slice of the ports of all targets: index/count, e.g. 2/4,
+    /// for splitting a scan across machines given the same targets & seed.
+    /// Check the merged results of all shards with `report --policy` instead of --policy
+    #[clap(long, conflicts_with = "policy")]
+    shard: Option<Shard>,
+
+    /// Seed of the order ports are probed in & of their assignment to shards
+    #[clap(long, default_value_t = 0)]
+    seed: u64,
+
+    /// File to save scan progress to periodically
+    #[clap(long)]
+    checkpoint: Option<PathBuf>,
+
+    /// Resume the scan saved in the checkpoint file, skipping probed ports
+    #[clap(long, requires = "checkpoint")]
+    resume: bool,
+
+    /// Database file to store the results of every run in
+    #[clap(long)]
+    database: Option<PathBuf>,
+
+    /// Policy file of allowed, forbidden & required ports per host to check the results against
+    #[clap(long, conflicts_with = "monitor")]
+    policy: Option<PathBuf>,
+
+    /// Re-run the scan every this many seconds until interrupted, reporting changes
+    #[clap(long, conflicts_with = "checkpoint", value_parser = parse_positive)]
+    monitor: Option<usize>,
+
+    /// File to append monitoring change events to, as json lines
+    #[clap(long, requires = "monitor")]
+    events: Option<PathBuf>,
+
+    /// Shell command to run per monitoring change event, with the event as json on stdin
+    #[clap(long, requires = "monitor")]
+    on_change: Option<String>,
+
+    /// Url to post monitoring change events to as json, http:// only
+    #[clap(long, requires = "monitor")]
+    webhook: Option<Url>,
+
+    /// Exit with a non-zero code when a target could not be scanned
+    #[clap(long)]
+    fail_on_error: bool,
+}
+
+/// Options of name resolution & host discovery
+#[derive(Args, Debug)]
+struct DnsArgs {
+    /// Only use ipv4 addresses
+    #[clap(short = '4', conflicts_with = "ipv6")]
+    ipv4: bool,
+
+    /// Only use ipv6 addresses
+    #[clap(short = '6')]
+    ipv6: bool,
+
+    /// Only use the first resolved address of a host name instead of all
+    #[clap(long)]
+    first_address: bool,
+
+    /// Dns server to resolve host names with instead of the system resolver
+    #[clap(long, value_parser = parse_resolver)]
+    resolver: Option<SocketAddr>,
+
+    /// Also use services advertised in well-known SRV records of host names
+    #[clap(long)]
+    srv: bool,
+
+    /// Wordlist to brute-force subdomains of host names with, one word per line
+    #[clap(long)]
+    subdomains: Option<PathBuf>,
+
+    /// Maximum amount of concurrent subdomain & reverse dns lookups
+    #[clap(long, default_value_t = 20, value_parser = parse_positive)]
+    dns_concurrency: usize,
+
+    /// Skip reverse dns lookups of responding hosts
+    #[clap(long)]
+    no_reverse_dns: bool,
+}
+
+impl DnsArgs {
+    /// Resolver for the address family options
+    fn resolver(&self) -> Resolver {
+        let family = match (self.ipv4, self.ipv6) {
+            (true, _) => Family::V4,
+            (_, true) => Family::V6,
+            _ => Family::Any,
+        };
+        Resolver::new(family, !self.first_address, self.resolver)
+    }
+
+    /// Discovery options, loading the subdomain wordlist
+    fn discovery(&self) -> Result<DiscoveryOptions, Error> {
+        let subdomains = match &self.subdomains {
+            Some(path) => load_wordlist(path)?,
+            None => Vec::new(),
+        };
+        Ok(DiscoveryOptions {
+            srv: self.srv,
+            subdomains,
+            concurrency: self.dns_concurrency,
+        })
+    }
+}
+
+/// Parse a dns server address, the port defaults to 53
+fn parse_resolver(s: &str) -> Result<SocketAddr, String> {
+    match s.parse::<IpAddr>() {
+        Ok(ip) => Ok(SocketAddr::new(ip, 53)),
+        Err(_) => s
+            .parse()
+            .map_err(|_| format!("invalid resolver address '{}'", s)),
+    }
+}
+
+/// Parse an amount of at least 1, e.g. of concurrent probes
+fn parse_positive(s: &str) -> Result<usize, String> {
+    match s.parse::<usize>() {
+        Ok(0) => Err("has to be at least 1".to_string()),
+        Ok(amount) => Ok(amount),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Load the words of a wordlist, skipping empty lines & comments
+fn load_wordlist(path: &Path) -> Result<Vec<String>, Error> {
+    let content = fs::read_to_string(path).map_err(|source| Error::Wordlist {
+        path: path.to_owned(),
+        source,
+    })?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|word| !word.is_empty() && !word.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Write to the file at `path` or stdout
+fn write_to(
+    path: Option<&Path>,
+    write: impl FnOnce(&mut dyn Write) -> io::Result<()>,
+) -> Result<(), Error> {
+    let written = match path {
+        Some(path) => fs::File::create(path).and_then(|file| {
+            let mut out = io::BufWriter::new(file);
+            write(&mut out)?;
+            out.flush()
+        }),
+        None => write(&mut io::stdout().lock()),
+    };
+    written.map_err(|source| Error::Output {
+        path: path.unwrap_or(Path::new("stdout")).to_owned(),
+        source,
+    })
+}
+
+/// Load the embedded services & the services files
+fn load_registry(global: &GlobalArgs) -> Result<ServiceRegistry, Error> {
+    let mut registry = ServiceRegistry::embedded();
+    for path in global.services_file.iter() {
+        registry.extend(ServiceRegistry::load(path)?);
+    }
+    Ok(registry)
+}
+
+/// Exit code of results: interrupted, breaking a policy, failed or successful
+fn results_code(complete: bool, violations: Option<&[Violation]>, failed: bool) -> ExitCode {
+    if !complete {
+        ExitCode::from(INTERRUPTED)
+    } else if let Some(code) = violations.and_then(exit_code) {
+        ExitCode::from(code)
+    } else if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
+async fn main() -> ExitCode {
     // Get arguments
-    let args = Args::parse();
+    let matches = Cli::command().get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    match execute(cli, &matches).await {
+        Ok(code) => code,
+        Err(error) => {
+            eprintln!("Error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Apply the selected profile & run the command
+async fn execute(cli: Cli, matches: &ArgMatches) -> Result<ExitCode, Error> {
+    let Cli {
+        command,
+        mut global,
+    } = cli;
 
-    // Get amount of common ports
-    let common_port_amount = match args.common {
-        Some(amount) => amount,
-        None => 1000,
+    // Use profile values for options not given
+    let config;
+    let profile = match &global.profile {
+        Some(name) => {
+            config = Config::load(&config_paths(), global.config.as_deref())?;
+            Some(config.profile(name)?)
+        }
+        None => None,
+    };
+    let (name, matches) = matches.subcommand().expect("Command is required");
+    if let Some(profile) = profile {
+        profile.apply_global(&mut global, matches);
+    }
+    let cli_command = Cli::command();
+    let subcommand = cli_command
+        .find_subcommand(name)
+        .expect("Matched commands exist");
+    let invalid_profile = |reason| Error::InvalidProfile {
+        name: global.profile.clone().unwrap_or_default(),
+        reason,
     };
 
-    // Get port vector
-    let mut ports_to_scan = match args.port {
-        Some(p) => p
+    match command {
+        Command::Scan(mut args) => {
+            if let Some(profile) = profile {
+                profile
+                    .apply_scan(&mut args, subcommand, matches)
+                    .map_err(invalid_profile)?;
+            }
+            run(&global, *args).await
+        }
+        Command::Discover { address, mut dns } => {
+            if let Some(profile) = profile {
+                profile
+                    .apply_dns(&mut dns, subcommand, matches)
+                    .map_err(invalid_profile)?;
+            }
+            run_discover(&global, &address, &dns).await
+        }
+        Command::Diff { old, new } => run_diff(&global, &old, &new),
+        Command::Report { results, policy } => run_report(&global, &results, policy.as_deref()),
+        Command::Query {
+            database,
+            port,
+            host,
+            state,
+            since,
+            first_seen,
+        } => {
+            let query = Query {
+                port,
+                host,
+                state,
+                since: since.map(|age| unix_time().saturating_sub(age)),
+                first_seen,
+            };
+            run_query(&global, &database, &query)
+        }
+        Command::Serve {
+            listen,
+            max_jobs,
+            max_queued,
+            concurrency,
+            max_targets,
+            keep_finished,
+            mut dns,
+        } => {
+            if let Some(profile) = profile {
+                profile
+                    .apply_dns(&mut dns, subcommand, matches)
+                    .map_err(invalid_profile)?;
+            }
+            let limits = Limits {
+                jobs: max_jobs,
+                queued: max_queued,
+                concurrency,
+                targets: max_targets,
+                finished: keep_finished,
+            };
+            run_serve(&global, listen, limits, &dns).await
+        }
+        Command::Coordinate {
+            address,
+            port,
+            common,
+            listen,
+            unit_size,
+            lease,
+            mut dns,
+        } => {
+            if let Some(profile) = profile {
+                profile
+                    .apply_dns(&mut dns, subcommand, matches)
+                    .map_err(invalid_profile)?;
+            }
+            let registry = load_registry(&global)?;
+            let ports = ports_to_scan(&registry, port.as_deref(), common.unwrap_or(1000));
+            let units = Units {
+                size: unit_size,
+                lease: Duration::from_secs(lease),
+            };
+            run_coordinate(&global, &address, ports, listen, units, &dns).await
+        }
+        Command::Work {
+            coordinator,
+            name,
+            concurrency,
+            no_reverse_dns,
+            dns_concurrency,
+        } => {
+            let worker = Worker {
+                name: name.unwrap_or_else(worker_name),
+                coordinator,
+                connector: Arc::new(Connector::default()),
+                resolver: Resolver::new(Family::Any, true, None),
+                concurrency,
+                reverse_dns: !no_reverse_dns,
+                dns_concurrency,
+            };
+            run_work(&worker).await
+        }
+        Command::Services { query, protocol } => {
+            run_services(&global, query.as_deref().unwrap_or(""), protocol)
+        }
+    }
+}
+
+/// Resolve & list hosts
+async fn run_discover(
+    global: &GlobalArgs,
+    address: &[String],
+    dns: &DnsArgs,
+) -> Result<ExitCode, Error> {
+    let resolver = dns.resolver();
+    let Discovery { targets, failures } =
+        discover_targets(address, Arc::new([]), &resolver, &dns.discovery()?).await;
+
+    // Reverse dns lookup of every host, none were probed
+    let mut targets = dedup_targets(targets);
+    if !dns.no_reverse_dns {
+        let lookups = targets
             .iter()
-            .map(|nr| Port {
-                service: "unknown".to_string(),
-                number: *nr,
-                is_open: None,
-            })
-            .collect(),
-        None => Vec::new(),
-    };
-    ports_to_scan.append(&mut get_common_ports(common_port_amount).to_owned());
-
-    // Dns lookup
-    let mut targets = Vec::new();
-    for url in args.address.iter() {
-        let target = format!("{}:0", url);
-        let mut addresses = lookup_host(target).await?;
-        while let Some(address) = addresses.next() {
-            targets.push(Target {
-                name: url.to_string(),
-                address: address,
-                ports: ports_to_scan.to_owned(),
-            });
+            .map(|target| resolver.reverse_lookup(target.address.ip()));
+        let hostnames = join_all(lookups).await;
+        for (target, hostname) in targets.iter_mut().zip(hostnames) {
+            target.hostname = hostname;
         }
     }
 
-    // Scan targets
-    let scan_res = scan_targets(targets).await;
+    let mut results: Vec<Result<Target, TargetFailure>> = targets.into_iter().map(Ok).collect();
+    results.extend(failures.into_iter().map(Err));
+    write_to(global.output.as_deref(), |out| {
+        write_hosts(out, global.format, &results)
+    })?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Compare two saved scans
+fn run_diff(global: &GlobalArgs, old: &Path, new: &Path) -> Result<ExitCode, Error> {
+    let old = load_complete(old)?.targets()?;
+    let new = load_complete(new)?.targets()?;
+    let diff = diff_targets(&old, &new);
+    write_to(global.output.as_deref(), |out| {
+        write_diff(out, global.format, &diff)
+    })?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Load saved results, which have to be of a complete scan
+fn load_complete(path: &Path) -> Result<Report, Error> {
+    let report = Report::load(path)?;
+    if !report.complete || report.targets.iter().any(|target| target.unscanned > 0) {
+        return Err(Error::IncompleteResults {
+            path: path.to_owned(),
+        });
+    }
+    Ok(report)
+}
+
+/// Show saved scans merged into one, checking complete scans against a policy
+fn run_report(
+    global: &GlobalArgs,
+    results: &[PathBuf],
+    policy: Option<&Path>,
+) -> Result<ExitCode, Error> {
+    let mut report = Report::load(&results[0])?;
+    for path in results[1..].iter() {
+        report.merge(Report::load(path)?);
+    }
+    if let Some(path) = policy {
+        let policy = Policy::load(path)?;
+        report.violations =
+            report
+                .complete
+                .then(|| report.targets())
+                .transpose()?
+                .map(|targets| {
+                    let mut violations = policy.check(&targets);
+                    violations.extend(policy.check_failures(
+                        report.failures.iter().map(|failure| failure.name.as_str()),
+                    ));
+                    violations
+                });
+    }
+    write_to(global.output.as_deref(), |out| {
+        write_report(out, global.format, &report)
+    })?;
+    Ok(results_code(
+        report.complete,
+        report.violations.as_deref(),
+        false,
+    ))
+}
+
+/// List stored ports
+fn run_query(global: &GlobalArgs, database: &Path, query: &Query) -> Result<ExitCode, Error> {
+    let sightings = Database::open(database)?.query(query)?;
+    write_to(global.output.as_deref(), |out| {
+        write_sightings(out, global.format, &sightings)
+    })?;
+    Ok(ExitCode::SUCCESS)
+}
 
-    // Print output
-    for target in scan_res.iter() {
-        println!(
-            "Open tcp ports for {} ({}):",
-            target.address.ip(),
-            target.name
-        );
-        for port in target.ports.iter() {
-            if port.is_open.expect("No port scanning result available") {
-                println!("  {}\t{}", port.number, port.service);
+/// Serve the scan api until interrupted
+async fn run_serve(
+    global: &GlobalArgs,
+    listen: SocketAddr,
+    limits: Limits,
+    dns: &DnsArgs,
+) -> Result<ExitCode, Error> {
+    let connector = Connector::default();
+    connector.check()?;
+    let server = Server::new(
+        load_registry(global)?,
+        Arc::new(connector),
+        dns.resolver(),
+        dns.discovery()?,
+        !dns.no_reverse_dns,
+        limits,
+    );
+
+    let bind_error = |source| Error::Bind {
+        local: listen.to_string(),
+        source,
+    };
+    let listener = TcpListener::bind(listen).await.map_err(bind_error)?;
+    eprintln!("Listening on http://{}", listen);
+    let cancel = CancellationToken::new();
+    cancel_on_interrupt(cancel.clone());
+    serve(listener, Arc::new(server), cancel)
+        .await
+        .map_err(bind_error)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// How to split targets among workers
+struct Units {
+    /// Targets per unit
+    size: usize,
+    /// Time a unit stays assigned without heartbeat
+    lease: Duration,
+}
+
+/// Coordinate workers until all targets are scanned or interrupted
+async fn run_coordinate(
+    global: &GlobalArgs,
+    address: &[String],
+    ports: Vec<Port>,
+    listen: SocketAddr,
+    units: Units,
+    dns: &DnsArgs,
+) -> Result<ExitCode, Error> {
+    let resolver = dns.resolver();
+    let Discovery { targets, failures } =
+        discover_targets(address, ports.into(), &resolver, &dns.discovery()?).await;
+    let targets = dedup_targets(targets);
+
+    let bind_error = |source| Error::Bind {
+        local: listen.to_string(),
+        source,
+    };
+    let listener = TcpListener::bind(listen).await.map_err(bind_error)?;
+    let coordinator = Coordinator::new(work_units(&targets, units.size), units.lease);
+    eprintln!("Listening for workers on http://{}", listen);
+    let cancel = CancellationToken::new();
+    cancel_on_interrupt(cancel.clone());
+    let mut report = coordinate(listener, Arc::new(coordinator), &cancel)
+        .await
+        .map_err(bind_error)?;
+
+    let failures: Vec<Result<Target, TargetFailure>> = failures.into_iter().map(Err).collect();
+    report
+        .failures
+        .extend(Report::new(&failures, true).failures);
+    write_to(global.output.as_deref(), |out| {
+        write_report(out, global.format, &report)
+    })?;
+    Ok(results_code(report.complete, None, false))
+}
+
+/// Scan work units until the coordinator is done or interrupted
+async fn run_work(worker: &Worker) -> Result<ExitCode, Error> {
+    let cancel = CancellationToken::new();
+    cancel_on_interrupt(cancel.clone());
+    let scanned = worker.work(&cancel).await?;
+    eprintln!("Scanned {} units", scanned);
+    match cancel.is_cancelled() {
+        true => Ok(ExitCode::from(INTERRUPTED)),
+        false => Ok(ExitCode::SUCCESS),
+    }
+}
+
+/// List known services
+fn run_services(
+    global: &GlobalArgs,
+    query: &str,
+    protocol: Option<Protocol>,
+) -> Result<ExitCode, Error> {
+    let registry = load_registry(global)?;
+    let services = registry.search(query, protocol);
+    write_to(global.output.as_deref(), |out| {
+        write_services(out, global.format, &services)
+    })?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Ports given by number followed by the most common ports
+fn ports_to_scan(registry: &ServiceRegistry, numbers: Option<&[u16]>, common: usize) -> Vec<Port> {
+    let mut ports: Vec<Port> = numbers
+        .unwrap_or_default()
+        .iter()
+        .map(|&number| Port {
+            service: registry
+                .service_name(number, Protocol::Tcp)
+                .unwrap_or("unknown"),
+            number,
+        })
+        .collect();
+    ports.append(&mut registry.get_common_ports(Protocol::Tcp, common));
+    ports
+}
+
+/// What every scan of a run shares
+struct Setup {
+    registry: ServiceRegistry,
+    connector: Arc<Connector>,
+    ports: Arc<[Port]>,
+    resolver: Resolver,
+    discovery: DiscoveryOptions,
+    policy: Option<Policy>,
+    database: Option<Mutex<Database>>,
+}
+
+impl Setup {
+    /// Load services, check the source of probes & collect the ports to scan
+    async fn new(global: &GlobalArgs, args: &ScanArgs) -> Result<Setup, Error> {
+        // Load service registry
+        let registry = load_registry(global)?;
+
+        // Check the source of probes before resolving anything
+        let ssh = match &args.ssh_jump {
+            Some(jump) => {
+                let ssh =
+                    SshJump::new(args.ssh_command.clone(), jump).map_err(|reason| Error::Ssh {
+                        destination: jump.clone(),
+                        reason,
+                    })?;
+                ssh.connect().await?;
+                Some(Arc::new(ssh))
+            }
+            None => None,
+        };
+        let connector = Connector {
+            source_ip: args.source_ip,
+            source_port: args.source_port,
+            interface: args.interface.clone(),
+            proxies: args.proxy.clone(),
+            ssh,
+            ..Connector::default()
+        };
+        connector.check()?;
+
+        // Get port vector
+        let mut ports_to_scan =
+            ports_to_scan(&registry, args.port.as_deref(), args.common.unwrap_or(1000));
+
+        // Always scan the ports a policy forbids or requires
+        let policy = match &args.policy {
+            Some(path) => Some(Policy::load(path)?),
+            None => None,
+        };
+        for number in policy.iter().flat_map(Policy::ports) {
+            if !ports_to_scan.iter().any(|port| port.number == number) {
+                ports_to_scan.push(Port {
+                    service: registry
+                        .service_name(number, Protocol::Tcp)
+                        .unwrap_or("unknown"),
+                    number,
+                });
             }
         }
-        print!("{}", '\n');
+
+        Ok(Setup {
+            registry,
+            connector: Arc::new(connector),
+            ports: ports_to_scan.into(),
+            resolver: args.dns.resolver(),
+            discovery: args.dns.discovery()?,
+            policy,
+            database: match &args.database {
+                Some(path) => Some(Mutex::new(Database::open(path)?)),
+                None => None,
+            },
+        })
     }
+}
+
+/// Run a scan, or monitor with repeated scans
+async fn run(global: &GlobalArgs, args: ScanArgs) -> Result<ExitCode, Error> {
+    let setup = Setup::new(global, &args).await?;
+    let cancel = CancellationToken::new();
+    cancel_on_interrupt(cancel.clone());
+
+    // Monitor until interrupted
+    if let Some(interval) = args.monitor {
+        let alerts = Alerts {
+            format: global.format,
+            output: global.output.clone(),
+            events: args.events.clone(),
+            command: args.on_change.clone(),
+            webhook: args.webhook.clone(),
+        };
+        monitor(
+            Duration::from_secs(interval as u64),
+            &alerts,
+            &cancel,
+            || async {
+                setup.resolver.clear_cache();
+                let (results, complete) = scan(&args, &setup, &cancel).await?;
+                Ok(complete.then(|| results.into_iter().filter_map(Result::ok).collect()))
+            },
+        )
+        .await?;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    // Check policy of complete scans
+    let (scan_res, complete) = scan(&args, &setup, &cancel).await?;
+    let violations = match &setup.policy {
+        Some(policy) if complete => {
+            let mut violations = policy.check(scan_res.iter().filter_map(|res| res.as_ref().ok()));
+            violations.extend(
+                policy.check_failures(
+                    scan_res
+                        .iter()
+                        .filter_map(|res| res.as_ref().err())
+                        .map(|failure| failure.name.as_str()),
+                ),
+            );
+            Some(violations)
+        }
+        _ => None,
+    };
+
+    // Write output
+    write_to(global.output.as_deref(), |out| {
+        write_results(
+            out,
+            global.format,
+            &scan_res,
+            complete,
+            violations.as_deref(),
+        )
+    })?;
 
     // End program
-    Ok(())
+    let failed = scan_res.iter().any(|result| result.is_err());
+    Ok(results_code(
+        complete,
+        violations.as_deref(),
+        failed && args.fail_on_error,
+    ))
+}
+
+/// Discover & scan the targets, returning the results & whether the scan completed
+async fn scan(
+    args: &ScanArgs,
+    setup: &Setup,
+    cancel: &CancellationToken,
+) -> Result<(Vec<Result<Target, TargetFailure>>, bool), Error> {
+    // Dns lookup, recording failed targets
+    let started = unix_time();
+    let Discovery { targets, failures } = discover_targets(
+        &args.address,
+        setup.ports.clone(),
+        &setup.resolver,
+        &setup.discovery,
+    )
+    .await;
+
+    // Scan every address once, only the ports of our shard
+    let mut targets = dedup_targets(targets);
+    if let Some(shard) = args.shard {
+        targets = shard.select(targets, args.seed);
+    }
+
+    // Restore & save progress
+    let mut hooks = ScanHooks {
+        cancel: cancel.clone(),
+        probes: None,
+        seed: args.seed,
+    };
+    let checkpointer = match &args.checkpoint {
+        Some(path) => {
+            if args.resume {
+                Checkpoint::load(path)?.restore(&mut targets, &setup.registry);
+            }
+            let (probes_tx, probes_rx) = mpsc::unbounded_channel();
+            hooks.probes = Some(probes_tx);
+            Some(spawn_checkpointer(
+                path.clone(),
+                Checkpoint::new(&targets),
+                probes_rx,
+            ))
+        }
+        None => None,
+    };
+
+    // Scan targets, an interrupt keeps the results so far
+    let connector = setup.connector.clone();
+    let mut scan_res = scan_targets(targets, args.concurrency, connector, hooks).await;
+    let complete = !cancel.is_cancelled();
+    if let Some(checkpointer) = checkpointer {
+        // The results are still worth writing without a checkpoint
+        if let Err(error) = checkpointer.await.expect("Checkpointer panicked") {
+            eprintln!("Saving checkpoint failed: {}", error);
+        }
+    }
+
+    // Reverse dns lookup
+    if !args.dns.no_reverse_dns && complete {
+        setup
+            .resolver
+            .reverse_lookup_targets(
+                scan_res.iter_mut().filter_map(|res| res.as_mut().ok()),
+                setup.discovery.concurrency,
+            )
+            .await;
+    }
+    scan_res.extend(failures.into_iter().map(Err));
+
+    // Store run
+    if let Some(database) = &setup.database {
+        database.lock().expect("Database poisoned").record_run(
+            started,
+            unix_time(),
+            complete,
+            &scan_res,
+        )?;
+    }
+    Ok((scan_res, complete))
 }
@@ -0,0 +1,264 @@
+use std::fs::OpenOptions;
+use std::future::Future;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tokio::time::{sleep, Duration};
+use tokio_util::sync::CancellationToken;
+
+use crate::database::unix_time;
+use crate::diff::{diff_targets, write_diff, Diff, HostDiff};
+use crate::error::Error;
+use crate::http::{post_json, Url};
+use crate::output::Format;
+use crate::port::Target;
+
+/// Kind of change of a host between two runs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Appeared,
+    Disappeared,
+    Changed,
+}
+
+/// A changed host, sent to the alert sinks as json
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeEvent {
+    /// Seconds since the unix epoch
+    pub time: u64,
+    pub kind: ChangeKind,
+    #[serde(flatten)]
+    pub host: HostDiff,
+}
+
+/// Change events of a diff
+fn change_events(diff: &Diff, time: u64) -> Vec<ChangeEvent> {
+    let kinds = [
+        (ChangeKind::Appeared, &diff.appeared),
+        (ChangeKind::Disappeared, &diff.disappeared),
+        (ChangeKind::Changed, &diff.changed),
+    ];
+    kinds
+        .into_iter()
+        .flat_map(|(kind, hosts)| {
+            hosts.iter().map(move |host| ChangeEvent {
+                time,
+                kind,
+                host: host.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Where changes are reported, besides stdout
+#[derive(Debug, Clone)]
+pub struct Alerts {
+    /// Format of the changes
+    pub format: Format,
+    /// File to append the changes to instead of stdout
+    pub output: Option<PathBuf>,
+    /// File to append events to, one json object per line
+    pub events: Option<PathBuf>,
+    /// Shell command to run per event, with the event on stdin
+    pub command: Option<String>,
+    /// Url to post every event to
+    pub webhook: Option<Url>,
+}
+
+impl Alerts {
+    /// Report the changes of a diff to every sink, returning the failed sinks
+    pub async fn send(&self, diff: &Diff) -> Vec<Error> {
+        let mut errors = Vec::new();
+        if diff.is_empty() {
+            return errors;
+        }
+        match &self.output {
+            Some(path) => {
+                if let Err(e) = append_diff(path, self.format, diff) {
+                    errors.push(alert_error(path.display(), e));
+                }
+            }
+            None => {
+                if let Err(e) = write_diff(&mut io::stdout().lock(), self.format, diff) {
+                    errors.push(alert_error("stdout", e));
+                }
+            }
+        }
+
+        for event in change_events(diff, unix_time()) {
+            let json = serde_json::to_vec(&event).expect("Events serialize");
+            if let Some(path) = &self.events {
+                if let Err(e) = append_line(path, &json) {
+                    errors.push(alert_error(path.display(), e));
+                }
+            }
+            if let Some(command) = &self.command {
+                if let Err(e) = run_command(command, &json).await {
+                    errors.push(alert_error(command, e));
+                }
+            }
+            if let Some(url) = &self.webhook {
+                match post_json(url, &json).await {
+                    Ok(200..=299) => {}
+                    Ok(status) => errors.push(alert_error(url, format!("status {}", status))),
+                    Err(e) => errors.push(alert_error(url, e)),
+                }
+            }
+        }
+        errors
+    }
+}
+
+/// Error of an alert sink
+fn alert_error(sink: impl ToString, reason: impl ToString) -> Error {
+    Error::Alert {
+        sink: sink.to_string(),
+        reason: reason.to_string(),
+    }
+}
+
+/// Append a line to a file
+fn append_line(path: &PathBuf, line: &[u8]) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(line)?;
+    file.write_all(b"\n")
+}
+
+/// Append the changes of a diff to a file
+fn append_diff(path: &PathBuf, format: Format, diff: &Diff) -> io::Result<()> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let mut out = io::BufWriter::new(file);
+    write_diff(&mut out, format, diff)?;
+    out.flush()
+}
+
+/// Run a shell command with `input` on stdin, failing on a non-zero exit
+async fn run_command(command: &str, input: &[u8]) -> io::Result<()> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .spawn()?;
+    let mut stdin = child.stdin.take().expect("Stdin is piped");
+    stdin.write_all(input).await?;
+    drop(stdin);
+    let status = child.wait().await?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(io::Error::other(format!("exited with {}", status)))
+    }
+}
+
+/// Run `scan` every `interval` until cancelled, alerting about the changes
+/// between consecutive runs
+///
+/// `scan` returns `None` when it was interrupted, which ends monitoring.
+pub async fn monitor<F, Fut>(
+    interval: Duration,
+    alerts: &Alerts,
+    cancel: &CancellationToken,
+    mut scan: F,
+) -> Result<(), Error>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<Option<Vec<Target>>, Error>>,
+{
+    let mut previous: Option<Vec<Target>> = None;
+    loop {
+        let Some(targets) = scan().await? else {
+            return Ok(());
+        };
+        if let Some(previous) = &previous {
+            for error in alerts.send(&diff_targets(previous, &targets)).await {
+                eprintln!("Alert failed: {}", error);
+            }
+        }
+        previous = Some(targets);
+
+        tokio::select! {
+            _ = sleep(interval) => {}
+            _ = cancel.cancelled() => return Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::http::stand_in::spawn_webhook;
+    use crate::port::{Port, PortState};
+    use std::sync::Arc;
+
+    /// Target with all given ports open
+    fn target(ports: &[u16]) -> Target {
+        let ports: Arc<[Port]> = ports
+            .iter()
+            .map(|&number| Port {
+                service: "unknown",
+                number,
+            })
+            .collect();
+        let mut target = Target::new("host".to_string(), "192.0.2.1:0".parse().unwrap(), ports);
+        for index in 0..target.ports.len() {
+            target.states.set(index, PortState::Open);
+        }
+        target
+    }
+
+    /// Check that changes between runs are posted to the webhook & appended to the events file
+    #[tokio::test]
+    async fn monitor_alerts_changes() {
+        let (address, mut bodies) = spawn_webhook().await;
+        let events = std::env::temp_dir().join(format!("events-{}.jsonl", std::process::id()));
+        let output = std::env::temp_dir().join(format!("changes-{}.json", std::process::id()));
+        let alerts = Alerts {
+            format: Format::Json,
+            output: Some(output.clone()),
+            events: Some(events.clone()),
+            command: None,
+            webhook: Some(format!("http://{}/alerts", address).parse().unwrap()),
+        };
+
+        let mut runs = vec![target(&[22]), target(&[22]), target(&[22, 443])].into_iter();
+        let cancel = CancellationToken::new();
+        monitor(Duration::from_millis(1), &alerts, &cancel, || {
+            let run = runs.next().map(|target| vec![target]);
+            async move { Ok(run) }
+        })
+        .await
+        .unwrap();
+
+        let event: ChangeEvent = serde_json::from_str(&bodies.recv().await.unwrap()).unwrap();
+        assert_eq!(event.kind, ChangeKind::Changed);
+        assert_eq!(event.host.opened[0].number, 443);
+        assert!(bodies.try_recv().is_err());
+
+        let saved = std::fs::read_to_string(&events).unwrap();
+        std::fs::remove_file(&events).unwrap();
+        assert_eq!(saved.lines().count(), 1);
+
+        let changes = std::fs::read_to_string(&output).unwrap();
+        std::fs::remove_file(&output).unwrap();
+        let diff: Diff = serde_json::from_str(&changes).unwrap();
+        assert_eq!(diff.changed[0].opened[0].number, 443);
+    }
+
+    /// Check that the command sink gets the event on stdin
+    #[tokio::test]
+    async fn command_receives_event() {
+        let path = std::env::temp_dir().join(format!("command-{}.json", std::process::id()));
+        run_command(&format!("cat > {}", path.display()), b"{}")
+            .await
+            .unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "{}");
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(run_command("exit 3", b"").await.is_err());
+    }
+}
@@ -0,0 +1,324 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::address::{display_ip, TargetSpec};
+use crate::common_ports::intern_name;
+use crate::error::Error;
+use crate::policy::{write_violations, Violation};
+use crate::port::{Port, PortState, Target, TargetFailure};
+
+/// Format of scan results
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    Json,
+}
+
+/// Scan results as written in the json format
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Report {
+    pub complete: bool,
+    pub targets: Vec<TargetReport>,
+    pub failures: Vec<FailureReport>,
+    /// Policy violations, when a policy was checked
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub violations: Option<Vec<Violation>>,
+}
+
+/// A scanned target in a report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TargetReport {
+    pub address: String,
+    pub names: Vec<String>,
+    pub hostname: Option<String>,
+    pub open_ports: Vec<PortReport>,
+    pub unscanned: usize,
+}
+
+/// An open port in a report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortReport {
+    pub number: u16,
+    pub service: String,
+}
+
+/// A target that could not be scanned in a report
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FailureReport {
+    pub name: String,
+    pub error: String,
+}
+
+impl Report {
+    /// Report of scan results, `complete` is false for interrupted runs
+    pub fn new(results: &[Result<Target, TargetFailure>], complete: bool) -> Report {
+        let mut report = Report {
+            complete,
+            targets: Vec::new(),
+            failures: Vec::new(),
+            violations: None,
+        };
+        for result in results.iter() {
+            match result {
+                Ok(target) => report.targets.push(TargetReport {
+                    address: display_ip(&target.address),
+                    names: target.names.clone(),
+                    hostname: target.hostname.clone(),
+                    open_ports: target
+                        .open_ports()
+                        .map(|port| PortReport {
+                            number: port.number,
+                            service: port.service.to_string(),
+                        })
+                        .collect(),
+                    unscanned: target.unscanned(),
+                }),
+                Err(failure) => report.failures.push(FailureReport {
+                    name: failure.name.clone(),
+                    error: failure.error.to_string(),
+                }),
+            }
+        }
+        report
+    }
+
+    /// Load a report saved in the json format
+    pub fn load(path: &Path) -> Result<Report, Error> {
+        let error = |source| Error::Results {
+            path: path.to_owned(),
+            source,
+        };
+        let content = fs::read(path).map_err(error)?;
+        serde_json::from_slice(&content).map_err(|e| error(e.into()))
+    }
+
+    /// Add the results of another report, e.g. of another shard of the same scan
+    ///
+    /// Targets with the same address are combined, checked policies are dropped.
+    pub fn merge(&mut self, other: Report) {
+        self.complete &= other.complete;
+        self.violations = None;
+        for target in other.targets {
+            let Some(merged) = self
+                .targets
+                .iter_mut()
+                .find(|merged| merged.address == target.address)
+            else {
+                self.targets.push(target);
+                continue;
+            };
+            for name in target.names {
+                if !merged.names.contains(&name) {
+                    merged.names.push(name);
+                }
+            }
+            merged.hostname = merged.hostname.take().or(target.hostname);
+            for port in target.open_ports {
+                if !merged.open_ports.contains(&port) {
+                    merged.open_ports.push(port);
+                }
+            }
+            merged.open_ports.sort_by_key(|port| port.number);
+            merged.unscanned += target.unscanned;
+        }
+        for failure in other.failures {
+            if !self.failures.contains(&failure) {
+                self.failures.push(failure);
+            }
+        }
+    }
+
+    /// Scanned targets of the report with their open ports
+    pub fn targets(&self) -> Result<Vec<Target>, Error> {
+        self.targets
+            .iter()
+            .map(|report| {
+                let invalid = |reason| Error::InvalidTarget {
+                    target: report.address.clone(),
+                    reason,
+                };
+                let address = match report.address.parse().map_err(invalid)? {
+                    TargetSpec::Address(address) => address,
+                    _ => return Err(invalid("not an address".to_string())),
+                };
+                let ports: Arc<[Port]> = report
+                    .open_ports
+                    .iter()
+                    .map(|port| Port {
+                        service: intern_name(&port.service),
+                        number: port.number,
+                    })
+                    .collect();
+                let mut target = Target::new(String::new(), address, ports);
+                target.names = report.names.clone();
+                target.hostname = report.hostname.clone();
+                for index in 0..target.ports.len() {
+                    target.states.set(index, PortState::Open);
+                }
+                Ok(target)
+            })
+            .collect()
+    }
+}
+
+/// Write scan results in `format`, `complete` is false for interrupted runs
+///
+/// `violations` are written after the results when a policy was checked.
+pub fn write_results(
+    out: &mut dyn Write,
+    format: Format,
+    results: &[Result<Target, TargetFailure>],
+    complete: bool,
+    violations: Option<&[Violation]>,
+) -> io::Result<()> {
+    let mut report = Report::new(results, complete);
+    report.violations = violations.map(<[Violation]>::to_vec);
+    write_report(out, format, &report)
+}
+
+/// Write a report in `format`
+pub fn write_report(out: &mut dyn Write, format: Format, report: &Report) -> io::Result<()> {
+    match format {
+        Format::Text => {
+            write_text(out, report)?;
+            match &report.violations {
+                Some(violations) => write_violations(out, violations),
+                None => Ok(()),
+            }
+        }
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, report)?;
+            writeln!(out)
+        }
+    }
+}
+
+/// Write a report as text, failed targets included
+fn write_text(out: &mut dyn Write, report: &Report) -> io::Result<()> {
+    for target in report.targets.iter() {
+        match &target.hostname {
+            Some(hostname) => writeln!(
+                out,
+                "Open tcp ports for {} ({}, ptr: {}):",
+                target.address,
+                target.names.join(", "),
+                hostname
+            )?,
+            None => writeln!(
+                out,
+                "Open tcp ports for {} ({}):",
+                target.address,
+                target.names.join(", ")
+            )?,
+        }
+        for port in target.open_ports.iter() {
+            writeln!(out, "  {}\t{}", port.number, port.service)?;
+        }
+        if target.unscanned > 0 {
+            writeln!(out, "  ({} ports not scanned)", target.unscanned)?;
+        }
+        writeln!(out)?;
+    }
+    for failure in report.failures.iter() {
+        writeln!(out, "Scan failed for {}: {}", failure.name, failure.error)?;
+        writeln!(out)?;
+    }
+    if !report.complete {
+        writeln!(out, "Scan interrupted, results are incomplete")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that an interrupted scan is marked incomplete in both formats
+    #[test]
+    fn write_incomplete() {
+        let ports: Arc<[Port]> = Arc::new([
+            Port {
+                service: "ssh",
+                number: 22,
+            },
+            Port {
+                service: "http",
+                number: 80,
+            },
+        ]);
+        let mut target = Target::new(
+            "host".to_string(),
+            "192.0.2.1:0".parse().unwrap(),
+            ports.clone(),
+        );
+        target.states.set(0, PortState::Open);
+        let results = vec![Ok(target)];
+
+        let mut text = Vec::new();
+        write_results(&mut text, Format::Text, &results, false, None).unwrap();
+        assert_eq!(
+            String::from_utf8(text).unwrap(),
+            "Open tcp ports for 192.0.2.1 (host):\n  22\tssh\n  (1 ports not scanned)\n\n\
+             Scan interrupted, results are incomplete\n"
+        );
+
+        let mut json = Vec::new();
+        write_results(&mut json, Format::Json, &results, false, None).unwrap();
+        let report: Report = serde_json::from_slice(&json).unwrap();
+        assert!(!report.complete);
+        assert_eq!(report.targets[0].unscanned, 1);
+        assert_eq!(report.targets[0].open_ports[0].number, 22);
+
+        let targets = report.targets().unwrap();
+        assert_eq!(targets[0].address, results[0].as_ref().unwrap().address);
+        assert_eq!(targets[0].open_ports().collect::<Vec<_>>(), vec![ports[0]]);
+    }
+
+    /// Check that reports of shards combine targets seen by several shards
+    #[test]
+    fn merge_reports() {
+        let port = |number| PortReport {
+            number,
+            service: "unknown".to_string(),
+        };
+        let target = |address: &str, open_ports, unscanned| TargetReport {
+            address: address.to_string(),
+            names: vec![address.to_string()],
+            hostname: None,
+            open_ports,
+            unscanned,
+        };
+        let mut report = Report {
+            complete: true,
+            targets: vec![target("192.0.2.1", vec![port(443)], 0)],
+            failures: Vec::new(),
+            violations: Some(Vec::new()),
+        };
+        report.merge(Report {
+            complete: false,
+            targets: vec![
+                target("192.0.2.2", vec![port(22)], 0),
+                target("192.0.2.1", vec![port(22)], 2),
+            ],
+            failures: vec![FailureReport {
+                name: "missing.test".to_string(),
+                error: "not found".to_string(),
+            }],
+            violations: None,
+        });
+
+        assert!(!report.complete);
+        assert_eq!(report.violations, None);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.targets.len(), 2);
+        assert_eq!(report.targets[0].open_ports, vec![port(22), port(443)]);
+        assert_eq!(report.targets[0].names, vec!["192.0.2.1"]);
+        assert_eq!(report.targets[0].unscanned, 2);
+    }
+}
@@ -0,0 +1,355 @@
+use std::fs;
+use std::io::{self, Write};
+use std::net::SocketAddr;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::address::{display_ip, network_contains, parse_network, TargetSpec};
+use crate::error::Error;
+use crate::port::Target;
+
+/// Exit code when ports are open against the policy
+pub const EXIT_OPEN_VIOLATION: u8 = 3;
+
+/// Exit code when only required ports are missing or could not be checked
+pub const EXIT_MISSING_REQUIRED: u8 = 4;
+
+/// Ports that hosts may, must not & must expose, read from a toml file:
+///
+/// ```toml
+/// [[rule]]
+/// hosts = ["10.0.0.0/24", "www.example.com"]
+/// allowed = [22, 80, 443]
+/// forbidden = [23]
+/// required = [443]
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Policy {
+    #[serde(rename = "rule", default)]
+    pub rules: Vec<Rule>,
+}
+
+/// Ports of the hosts matching any of `hosts`
+///
+/// Without `allowed` every port is allowed that is not forbidden.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Rule {
+    pub hosts: Vec<HostPattern>,
+    #[serde(default)]
+    pub allowed: Option<Vec<u16>>,
+    #[serde(default)]
+    pub forbidden: Vec<u16>,
+    #[serde(default)]
+    pub required: Vec<u16>,
+}
+
+/// Hosts a rule applies to: an address, a network or a name of the target
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub enum HostPattern {
+    Network { address: SocketAddr, prefix: u8 },
+    Name(String),
+}
+
+impl TryFrom<String> for HostPattern {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        if s.contains('/') {
+            let (address, prefix) = parse_network(&s)?;
+            return Ok(HostPattern::Network { address, prefix });
+        }
+        match s.parse::<TargetSpec>()? {
+            TargetSpec::Address(address) => {
+                let prefix = if address.is_ipv4() { 32 } else { 128 };
+                Ok(HostPattern::Network { address, prefix })
+            }
+            _ => Ok(HostPattern::Name(s.to_ascii_lowercase())),
+        }
+    }
+}
+
+impl HostPattern {
+    /// Check if the pattern matches the address, one of the names or the ptr name of `target`
+    fn matches(&self, target: &Target) -> bool {
+        match self {
+            HostPattern::Network { address, prefix } => {
+                network_contains(address, *prefix, &target.address)
+            }
+            HostPattern::Name(name) => target
+                .names
+                .iter()
+                .chain(target.hostname.iter())
+                .any(|target_name| target_name.eq_ignore_ascii_case(name)),
+        }
+    }
+
+    /// Check if the pattern matches one of the inputs of a failed target, joined by ", "
+    fn matches_failure(&self, failure: &str) -> bool {
+        failure.split(", ").any(|name| match self {
+            HostPattern::Network { address, prefix } => matches!(
+                name.parse::<TargetSpec>(),
+                Ok(TargetSpec::Address(target)) if network_contains(address, *prefix, &target)
+            ),
+            HostPattern::Name(pattern) => name.eq_ignore_ascii_case(pattern),
+        })
+    }
+}
+
+/// How a port breaks the policy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ViolationKind {
+    /// Open & forbidden
+    Forbidden,
+    /// Open & not allowed
+    Unexpected,
+    /// Required & not open
+    Missing,
+    /// Forbidden or required on a host that could not be scanned
+    Unscanned,
+}
+
+/// A port of a host that breaks the policy
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Violation {
+    pub address: String,
+    pub names: Vec<String>,
+    pub port: u16,
+    pub kind: ViolationKind,
+}
+
+impl Policy {
+    /// Load a policy file
+    pub fn load(path: &Path) -> Result<Policy, Error> {
+        let error = |reason: String| Error::Policy {
+            path: path.to_owned(),
+            reason,
+        };
+        let content = fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+        toml::from_str(&content).map_err(|e| error(e.message().to_string()))
+    }
+
+    /// Forbidden & required ports, which have to be scanned to check the policy
+    pub fn ports(&self) -> Vec<u16> {
+        let mut ports: Vec<u16> = self
+            .rules
+            .iter()
+            .flat_map(|rule| rule.forbidden.iter().chain(rule.required.iter()))
+            .copied()
+            .collect();
+        ports.sort_unstable();
+        ports.dedup();
+        ports
+    }
+
+    /// Check the open ports of targets against every matching rule
+    pub fn check<'a>(&self, targets: impl IntoIterator<Item = &'a Target>) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for target in targets {
+            let open: Vec<u16> = target.open_ports().map(|port| port.number).collect();
+            let mut found: Vec<(u16, ViolationKind)> = Vec::new();
+            for rule in self.rules.iter() {
+                if !rule.hosts.iter().any(|pattern| pattern.matches(target)) {
+                    continue;
+                }
+                for &port in open.iter() {
+                    if rule.forbidden.contains(&port) {
+                        found.push((port, ViolationKind::Forbidden));
+                    } else if let Some(allowed) = &rule.allowed {
+                        if !allowed.contains(&port) {
+                            found.push((port, ViolationKind::Unexpected));
+                        }
+                    }
+                }
+                for &port in rule.required.iter() {
+                    if !open.contains(&port) {
+                        found.push((port, ViolationKind::Missing));
+                    }
+                }
+            }
+            found.sort_unstable_by_key(|&(port, kind)| (port, kind as u8));
+            found.dedup();
+            violations.extend(found.into_iter().map(|(port, kind)| Violation {
+                address: display_ip(&target.address),
+                names: target.names.clone(),
+                port,
+                kind,
+            }));
+        }
+        violations
+    }
+
+    /// Check the names of failed targets, whose forbidden & required ports are unknown
+    pub fn check_failures<'a>(
+        &self,
+        failures: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for failure in failures {
+            let mut ports: Vec<u16> = self
+                .rules
+                .iter()
+                .filter(|rule| rule.hosts.iter().any(|p| p.matches_failure(failure)))
+                .flat_map(|rule| rule.forbidden.iter().chain(rule.required.iter()))
+                .copied()
+                .collect();
+            ports.sort_unstable();
+            ports.dedup();
+            violations.extend(ports.into_iter().map(|port| Violation {
+                address: failure.to_string(),
+                names: vec![failure.to_string()],
+                port,
+                kind: ViolationKind::Unscanned,
+            }));
+        }
+        violations
+    }
+}
+
+/// Exit code for violations, `None` when the policy holds
+pub fn exit_code(violations: &[Violation]) -> Option<u8> {
+    if violations.iter().any(|violation| {
+        matches!(
+            violation.kind,
+            ViolationKind::Forbidden | ViolationKind::Unexpected
+        )
+    }) {
+        Some(EXIT_OPEN_VIOLATION)
+    } else if !violations.is_empty() {
+        Some(EXIT_MISSING_REQUIRED)
+    } else {
+        None
+    }
+}
+
+/// Write policy violations as text
+pub fn write_violations(out: &mut dyn Write, violations: &[Violation]) -> io::Result<()> {
+    if violations.is_empty() {
+        return writeln!(out, "Policy holds");
+    }
+    writeln!(out, "Policy violations:")?;
+    for violation in violations.iter() {
+        let kind = match violation.kind {
+            ViolationKind::Forbidden => "forbidden port open",
+            ViolationKind::Unexpected => "unexpected port open",
+            ViolationKind::Missing => "required port not open",
+            ViolationKind::Unscanned => "not scanned, cannot check port",
+        };
+        writeln!(
+            out,
+            "  {} ({}): {} {}",
+            violation.address,
+            violation.names.join(", "),
+            kind,
+            violation.port
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::{Port, PortState};
+    use std::sync::Arc;
+
+    /// Target with all given ports open
+    fn target(name: &str, address: &str, ports: &[u16]) -> Target {
+        let ports: Arc<[Port]> = ports
+            .iter()
+            .map(|&number| Port {
+                service: "unknown",
+                number,
+            })
+            .collect();
+        let mut target = Target::new(name.to_string(), address.parse().unwrap(), ports);
+        for index in 0..target.ports.len() {
+            target.states.set(index, PortState::Open);
+        }
+        target
+    }
+
+    /// Check forbidden, unexpected & missing ports per matching rule
+    #[test]
+    fn check_policy() {
+        let policy: Policy = toml::from_str(
+            r#"
+            [[rule]]
+            hosts = ["10.0.0.0/8"]
+            forbidden = [23]
+
+            [[rule]]
+            hosts = ["WWW.example.com", "192.0.2.1"]
+            allowed = [80, 443]
+            required = [443]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(policy.ports(), vec![23, 443]);
+
+        let targets = vec![
+            target("www.example.com", "10.1.2.3:0", &[23, 80]),
+            target("db", "192.0.2.1:0", &[443]),
+            target("other", "192.0.2.2:0", &[23]),
+        ];
+        let violations = policy.check(&targets);
+        let found: Vec<(&str, u16, ViolationKind)> = violations
+            .iter()
+            .map(|v| (v.address.as_str(), v.port, v.kind))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("10.1.2.3", 23, ViolationKind::Forbidden),
+                ("10.1.2.3", 23, ViolationKind::Unexpected),
+                ("10.1.2.3", 443, ViolationKind::Missing),
+            ]
+        );
+        assert_eq!(exit_code(&violations), Some(EXIT_OPEN_VIOLATION));
+        assert_eq!(exit_code(&violations[2..]), Some(EXIT_MISSING_REQUIRED));
+        assert_eq!(exit_code(&[]), None);
+    }
+
+    /// Check that failed targets named by a rule break the policy
+    #[test]
+    fn check_failed_targets() {
+        let policy: Policy = toml::from_str(
+            r#"
+            [[rule]]
+            hosts = ["www.example.com", "10.0.0.0/8"]
+            forbidden = [23]
+            required = [443]
+            "#,
+        )
+        .unwrap();
+        let failures = ["WWW.example.com", "db, 10.1.2.3", "other.example.com"];
+
+        let violations = policy.check_failures(failures);
+        let found: Vec<(&str, u16)> = violations
+            .iter()
+            .map(|v| (v.address.as_str(), v.port))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("WWW.example.com", 23),
+                ("WWW.example.com", 443),
+                ("db, 10.1.2.3", 23),
+                ("db, 10.1.2.3", 443),
+            ]
+        );
+        assert!(violations
+            .iter()
+            .all(|v| v.kind == ViolationKind::Unscanned));
+        assert_eq!(exit_code(&violations), Some(EXIT_MISSING_REQUIRED));
+    }
+
+    /// Check that invalid host patterns are rejected
+    #[test]
+    fn invalid_policy() {
+        let result = toml::from_str::<Policy>("[[rule]]\nhosts = [\"10.0.0.0/33\"]\n");
+        assert!(result.is_err());
+    }
+}
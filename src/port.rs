@@ -1,43 +1,288 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::io::ErrorKind;
 use std::net::SocketAddr;
+use std::sync::Arc;
 
 use futures::future::join_all;
 
-use tokio::net::TcpStream;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, Semaphore};
 use tokio::time::Duration;
+use tokio_util::sync::CancellationToken;
 
-/// A tcp port with `is_open` flag
-#[derive(Debug, Clone, PartialEq)]
+use crate::connect::Connector;
+use crate::error::Error;
+use crate::shard::{host_key, Permutation, Shard};
+
+/// A tcp port with service name & number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Port {
-    pub service: String,
+    pub service: &'static str,
     pub number: u16,
-    pub is_open: Option<bool>,
+}
+
+/// Scan state of a port
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortState {
+    Unknown,
+    Open,
+    Closed,
+    Filtered,
+}
+
+impl PortState {
+    /// Decode a state from its 2 bit representation
+    fn from_bits(bits: u64) -> PortState {
+        match bits {
+            1 => PortState::Open,
+            2 => PortState::Closed,
+            3 => PortState::Filtered,
+            _ => PortState::Unknown,
+        }
+    }
+
+    /// Encode a state in 2 bits
+    fn to_bits(self) -> u64 {
+        match self {
+            PortState::Unknown => 0,
+            PortState::Open => 1,
+            PortState::Closed => 2,
+            PortState::Filtered => 3,
+        }
+    }
+}
+
+/// States of a port list, packed in 2 bits per port
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortStates {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl PortStates {
+    /// Ports per 64 bit word
+    const PER_WORD: usize = 32;
+
+    /// `len` ports in the `Unknown` state
+    pub fn new(len: usize) -> PortStates {
+        PortStates {
+            words: vec![0; len.div_ceil(Self::PER_WORD)],
+            len,
+        }
+    }
+
+    /// Get the state of the port at `index`
+    pub fn get(&self, index: usize) -> PortState {
+        assert!(index < self.len, "Port index out of range");
+        let shift = (index % Self::PER_WORD) * 2;
+        PortState::from_bits((self.words[index / Self::PER_WORD] >> shift) & 0b11)
+    }
+
+    /// Set the state of the port at `index`
+    pub fn set(&mut self, index: usize, state: PortState) {
+        assert!(index < self.len, "Port index out of range");
+        let shift = (index % Self::PER_WORD) * 2;
+        let word = &mut self.words[index / Self::PER_WORD];
+        *word = (*word & !(0b11 << shift)) | (state.to_bits() << shift);
+    }
+
+    /// Packed states as hex, 4 ports per byte with the first port in the lowest bits
+    pub fn to_hex(&self) -> String {
+        let bytes = self.len.div_ceil(4);
+        let mut hex = String::with_capacity(bytes * 2);
+        for index in 0..bytes {
+            let byte = (self.words[index / 8] >> ((index % 8) * 8)) & 0xff;
+            write!(hex, "{:02x}", byte).expect("Strings are writable");
+        }
+        hex
+    }
+
+    /// States of `len` ports packed by [`PortStates::to_hex`], `None` for invalid hex
+    pub fn from_hex(hex: &str, len: usize) -> Option<PortStates> {
+        if hex.len() != len.div_ceil(4) * 2 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let mut states = PortStates::new(len);
+        for (index, pair) in hex.as_bytes().chunks(2).enumerate() {
+            let byte = u64::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+            states.words[index / 8] |= byte << ((index % 8) * 8);
+        }
+        // Bits past the last port are unused
+        if !len.is_multiple_of(Self::PER_WORD) {
+            let used = (len % Self::PER_WORD) * 2;
+            *states.words.last_mut()? &= (1 << used) - 1;
+        }
+        Some(states)
+    }
 }
 
 /// A target consisting out of:
 /// - An address
-/// - A vector of ports to scan
+/// - A list of ports to scan, shared between targets
+/// - The scan state of each port
+///
+/// `names` holds every input that resolved to the address,
+/// `hostname` the reverse lookup result of the address
 #[derive(Debug, Clone, PartialEq)]
 pub struct Target {
+    pub names: Vec<String>,
+    pub hostname: Option<String>,
+    pub address: SocketAddr,
+    pub ports: Arc<[Port]>,
+    pub states: PortStates,
+    /// Shard & seed of the ports to scan, ports of other shards are skipped
+    pub shard: Option<(Shard, u64)>,
+}
+
+impl Target {
+    /// Target with all ports in the `Unknown` state
+    pub fn new(name: String, address: SocketAddr, ports: Arc<[Port]>) -> Target {
+        let states = PortStates::new(ports.len());
+        Target {
+            names: vec![name],
+            hostname: None,
+            address,
+            ports,
+            states,
+            shard: None,
+        }
+    }
+
+    /// Check if the port at `index` belongs to the target's shard
+    pub fn owns(&self, index: usize) -> bool {
+        self.shard.is_none_or(|(shard, seed)| {
+            shard.contains(seed, self.address.ip(), self.ports[index].number)
+        })
+    }
+
+    /// Check if any port responded, open or closed
+    pub fn is_responding(&self) -> bool {
+        (0..self.ports.len())
+            .any(|index| matches!(self.states.get(index), PortState::Open | PortState::Closed))
+    }
+
+    /// Amount of ports of the shard left unscanned, e.g. by an interrupted run
+    pub fn unscanned(&self) -> usize {
+        (0..self.ports.len())
+            .filter(|index| self.states.get(*index) == PortState::Unknown && self.owns(*index))
+            .count()
+    }
+
+    /// Ports found open
+    pub fn open_ports(&self) -> impl Iterator<Item = Port> + '_ {
+        self.ports
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.states.get(*index) == PortState::Open)
+            .map(|(_, port)| *port)
+    }
+}
+
+/// Merge targets with the same address, keeping the names & ports of all merged targets
+pub fn dedup_targets(targets: Vec<Target>) -> Vec<Target> {
+    let mut indices: HashMap<SocketAddr, usize> = HashMap::new();
+    let mut unique: Vec<Target> = Vec::new();
+    for target in targets.into_iter() {
+        match indices.get(&target.address) {
+            Some(&index) => {
+                let existing = &mut unique[index];
+                for name in target.names {
+                    if !existing.names.contains(&name) {
+                        existing.names.push(name);
+                    }
+                }
+                if !Arc::ptr_eq(&existing.ports, &target.ports) {
+                    let mut ports = existing.ports.to_vec();
+                    for port in target.ports.iter() {
+                        if !ports.iter().any(|p| p.number == port.number) {
+                            ports.push(*port);
+                        }
+                    }
+                    if ports.len() != existing.ports.len() {
+                        existing.states = PortStates::new(ports.len());
+                        existing.ports = ports.into();
+                    }
+                }
+            }
+            None => {
+                indices.insert(target.address, unique.len());
+                unique.push(target);
+            }
+        }
+    }
+    unique
+}
+
+/// A target that could not be scanned
+#[derive(Debug)]
+pub struct TargetFailure {
     pub name: String,
+    pub error: Error,
+}
+
+/// The state of a probed port, `address` includes the port number
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbeResult {
     pub address: SocketAddr,
-    pub ports: Vec<Port>,
+    pub state: PortState,
 }
 
-/// Scan ports of multiple targets
-pub async fn scan_targets(targets: Vec<Target>) -> Vec<Target> {
+/// Hooks into a running scan
+#[derive(Debug, Clone, Default)]
+pub struct ScanHooks {
+    /// Cancelling aborts outstanding probes, their ports stay `Unknown`
+    pub cancel: CancellationToken,
+    /// Receives the state of every port as soon as it is probed
+    pub probes: Option<mpsc::UnboundedSender<ProbeResult>>,
+    /// Seed of the order ports are probed in, see [`Permutation`]
+    pub seed: u64,
+}
+
+/// Scan ports of multiple targets with at most `concurrency` probes in flight
+///
+/// Ports that already have a state, e.g. from a resumed scan, are skipped.
+pub async fn scan_targets(
+    targets: Vec<Target>,
+    concurrency: usize,
+    connector: Arc<Connector>,
+    hooks: ScanHooks,
+) -> Vec<Result<Target, TargetFailure>> {
     // Define input and output channels
-    let (targets_tx, mut targets_rx) = mpsc::channel(targets.len());
+    let (targets_tx, mut targets_rx) = mpsc::channel(targets.len().max(1));
+    let probes = Arc::new(Semaphore::new(concurrency));
 
     // Spawn scanning tasks
     let mut scan_tasks = Vec::new();
-    for target in targets.iter() {
+    for mut target in targets.into_iter() {
         let targets_tx = targets_tx.clone();
-        let mut target = target.to_owned();
+        let probes = probes.clone();
+        let connector = connector.clone();
+        let hooks = hooks.clone();
 
         let scan_task = tokio::spawn(async move {
-            target.ports = scan_ports(target.address, target.ports).await;
-            let _ = targets_tx.send(target).await;
+            let states = target.states.clone();
+            let ports = target.ports.clone();
+            let shard = target.shard;
+            let scan = scan_ports(
+                target.address,
+                ports,
+                shard,
+                states,
+                probes,
+                connector,
+                hooks,
+            );
+            let result = match scan.await {
+                Ok(states) => {
+                    target.states = states;
+                    Ok(target)
+                }
+                Err(error) => Err(TargetFailure {
+                    name: target.names.join(", "),
+                    error,
+                }),
+            };
+            let _ = targets_tx.send(result).await;
         });
         scan_tasks.push(scan_task);
     }
@@ -56,47 +301,362 @@ pub async fn scan_targets(targets: Vec<Target>) -> Vec<Target> {
     target_res
 }
 
-/// Scan multiple ports of a target
-async fn scan_ports(target: SocketAddr, ports: Vec<Port>) -> Vec<Port> {
-    // Define input and output channels
-    let (ports_tx, mut ports_rx) = mpsc::channel(ports.len());
-
-    // Spawn port scan tasks
-    let mut scan_tasks = Vec::new();
-    for port in ports.iter() {
-        let mut address = target;
-        address.set_port(port.number);
+/// Scan multiple ports of a target, in a stable pseudo-random order, skipping
+/// the ports of other shards
+///
+/// Fails on errors of the whole host, e.g. an unreachable network or a failing
+/// proxy, and when every port failed. Ports reporting their host unreachable
+/// are filtered, ports failing for other reasons, e.g. a lack of sockets, stay unknown.
+async fn scan_ports(
+    target: SocketAddr,
+    ports: Arc<[Port]>,
+    shard: Option<(Shard, u64)>,
+    mut states: PortStates,
+    probes: Arc<Semaphore>,
+    connector: Arc<Connector>,
+    hooks: ScanHooks,
+) -> Result<PortStates, Error> {
+    // Define output channel
+    let (states_tx, mut states_rx) = mpsc::channel(ports.len().max(1));
+    let owns = move |port: Port| {
+        shard.is_none_or(|(shard, seed)| shard.contains(seed, target.ip(), port.number))
+    };
+    let probed_amount = (0..ports.len())
+        .filter(|index| states.get(*index) != PortState::Unknown)
+        .count();
+    let port_amount = (0..ports.len())
+        .filter(|index| states.get(*index) == PortState::Unknown && owns(ports[*index]))
+        .count();
+    let order = Permutation::new(ports.len(), host_key(hooks.seed, target.ip()));
+    // Only resumed scans need to know which ports to skip
+    let probed = (probed_amount > 0).then(|| states.clone());
 
-        let ports_tx = ports_tx.clone();
-        let mut port = port.to_owned();
-        let scan_task = tokio::spawn(async move {
-            port.is_open = Some(scan_port(address).await);
-            let _ = ports_tx.send(port).await;
+    // Spawn port scan tasks, limited by the available probes
+    let cancel = hooks.cancel.clone();
+    let spawn_ports = ports.clone();
+    tokio::spawn(async move {
+        let unscanned = order.iter().filter(|index| {
+            probed
+                .as_ref()
+                .is_none_or(|probed| probed.get(*index) == PortState::Unknown)
+                && owns(spawn_ports[*index])
         });
-        scan_tasks.push(scan_task);
-    }
+        for index in unscanned {
+            let mut address = target;
+            let port = spawn_ports[index];
+            address.set_port(port.number);
 
-    // Wait for all tasks to finish & close channel
-    join_all(scan_tasks).await;
-    ports_rx.close();
+            let states_tx = states_tx.clone();
+            let connector = connector.clone();
+            let cancel = cancel.clone();
+            let probe = tokio::select! {
+                biased;
+                _ = cancel.cancelled() => break,
+                probe = probes.clone().acquire_owned() => probe.expect("Probes closed"),
+            };
+            if states_tx.is_closed() {
+                break;
+            }
+            tokio::spawn(async move {
+                let state = tokio::select! {
+                    biased;
+                    _ = cancel.cancelled() => return,
+                    state = scan_port(&connector, address) => state,
+                };
+                drop(probe);
+                let _ = states_tx.send((index, state)).await;
+            });
+        }
+    });
 
-    // Collect result
-    let mut ports_res = Vec::new();
-    while let Some(port) = ports_rx.recv().await {
-        ports_res.push(port);
+    // Collect result until all tasks dropped their sender, stop at the first error of the host
+    let mut port_failure = None;
+    let mut failed_amount = 0;
+    while let Some((index, state)) = states_rx.recv().await {
+        match state {
+            Ok(state) => {
+                states.set(index, state);
+                if let Some(probes) = &hooks.probes {
+                    let mut address = target;
+                    address.set_port(ports[index].number);
+                    let _ = probes.send(ProbeResult { address, state });
+                }
+            }
+            Err(error) if fails_host(&error) => return Err(error),
+            Err(error) => {
+                if is_host_unreachable(&error) {
+                    states.set(index, PortState::Filtered);
+                }
+                failed_amount += 1;
+                port_failure = Some(error);
+            }
+        }
     }
 
     // Return ports
-    ports_res
+    match port_failure {
+        Some(error) if failed_amount == port_amount => Err(error),
+        _ => Ok(states),
+    }
+}
+
+/// Check if an error applies to every port of a host, not only the probed one
+fn fails_host(error: &Error) -> bool {
+    match error {
+        Error::Unroutable { .. } => !is_host_unreachable(error),
+        // The source address cannot reach the address family of the host
+        Error::Connect { source, .. } => source.kind() == ErrorKind::InvalidInput,
+        _ => true,
+    }
+}
+
+/// Check if an error is a host unreachable report, which firewalls also send per port
+fn is_host_unreachable(error: &Error) -> bool {
+    matches!(error, Error::Unroutable { source, .. } if source.kind() == ErrorKind::HostUnreachable)
 }
 
 /// Scan a single port of a target
-async fn scan_port(target: SocketAddr) -> bool {
+///
+/// A timeout means filtered.
+async fn scan_port(connector: &Connector, target: SocketAddr) -> Result<PortState, Error> {
     let timeout = Duration::from_secs(3);
 
-    let is_open = matches!(
-        tokio::time::timeout(timeout, TcpStream::connect(&target)).await,
-        Ok(Ok(_)),
-    );
-    is_open
+    match tokio::time::timeout(timeout, connector.probe(target)).await {
+        Ok(state) => state,
+        Err(_) => Ok(PortState::Filtered),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that states are stored per port without touching neighbours & packed as hex
+    #[test]
+    fn port_states_set_get() {
+        let mut states = PortStates::new(70);
+        states.set(0, PortState::Open);
+        states.set(31, PortState::Closed);
+        states.set(32, PortState::Open);
+        states.set(69, PortState::Closed);
+        states.set(0, PortState::Closed);
+
+        assert_eq!(states.get(0), PortState::Closed);
+        assert_eq!(states.get(1), PortState::Unknown);
+        assert_eq!(states.get(31), PortState::Closed);
+        assert_eq!(states.get(32), PortState::Open);
+        assert_eq!(states.get(69), PortState::Closed);
+        assert_eq!(states.words.len(), 3);
+
+        assert_eq!(
+            PortStates::from_hex(&states.to_hex(), 70),
+            Some(states.clone())
+        );
+        assert_eq!(&states.to_hex()[..2], "02");
+        assert_eq!(states.to_hex().len(), 36);
+        assert_eq!(
+            PortStates::from_hex("ff", 3).unwrap().get(3 - 1),
+            PortState::Filtered
+        );
+        assert_eq!(
+            PortStates::from_hex("ff", 3).unwrap().words,
+            vec![0b11_1111]
+        );
+        assert_eq!(PortStates::from_hex("0", 3), None);
+        assert_eq!(PortStates::from_hex("zz", 3), None);
+    }
+
+    /// Check that only open ports are materialized
+    #[test]
+    fn target_open_ports() {
+        let ports: Arc<[Port]> = Arc::new([
+            Port {
+                service: "ssh",
+                number: 22,
+            },
+            Port {
+                service: "http",
+                number: 80,
+            },
+        ]);
+        let mut target = Target::new("localhost".to_string(), "[::1]:0".parse().unwrap(), ports);
+        target.states.set(0, PortState::Closed);
+        target.states.set(1, PortState::Open);
+
+        let open: Vec<Port> = target.open_ports().collect();
+        assert_eq!(open, vec![target.ports[1]]);
+    }
+
+    /// Check that targets with the same address are merged with all their names
+    #[test]
+    fn dedup_targets_by_address() {
+        let ports: Arc<[Port]> = Arc::new([]);
+        let address = "192.0.2.1:0".parse().unwrap();
+        let targets = vec![
+            Target::new("example.com".to_string(), address, ports.clone()),
+            Target::new(
+                "192.0.2.2".to_string(),
+                "192.0.2.2:0".parse().unwrap(),
+                ports.clone(),
+            ),
+            Target::new("www.example.com".to_string(), address, ports.clone()),
+            Target::new("example.com".to_string(), address, ports.clone()),
+        ];
+
+        let targets = dedup_targets(targets);
+        assert_eq!(targets.len(), 2);
+        assert_eq!(targets[0].names, vec!["example.com", "www.example.com"]);
+        assert_eq!(targets[1].names, vec!["192.0.2.2"]);
+    }
+
+    /// Check that merged targets scan the ports of both targets
+    #[test]
+    fn dedup_targets_merges_ports() {
+        let ssh = Port {
+            service: "ssh",
+            number: 22,
+        };
+        let ldap = Port {
+            service: "ldap",
+            number: 3890,
+        };
+        let address = "192.0.2.1:0".parse().unwrap();
+        let targets = vec![
+            Target::new("example.com".to_string(), address, Arc::new([ssh])),
+            Target::new(
+                "dc1.example.com".to_string(),
+                address,
+                Arc::new([ssh, ldap]),
+            ),
+        ];
+
+        let targets = dedup_targets(targets);
+        assert_eq!(targets[0].ports.as_ref(), &[ssh, ldap]);
+        assert_eq!(targets[0].states.len, 2);
+    }
+
+    /// Check that a listening port is open and a refusing port closed
+    #[tokio::test]
+    async fn scan_port_open_closed() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let open = listener.local_addr().unwrap();
+        let closed = {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            listener.local_addr().unwrap()
+        };
+
+        let connector = Connector::default();
+        assert_eq!(scan_port(&connector, open).await.unwrap(), PortState::Open);
+        assert_eq!(
+            scan_port(&connector, closed).await.unwrap(),
+            PortState::Closed
+        );
+    }
+
+    /// Check that a cancelled scan returns its targets with unscanned ports
+    #[tokio::test]
+    async fn scan_targets_cancelled() {
+        let ports: Arc<[Port]> = Arc::new([Port {
+            service: "http",
+            number: 80,
+        }]);
+        let target = Target::new(
+            "192.0.2.1".to_string(),
+            "192.0.2.1:0".parse().unwrap(),
+            ports,
+        );
+        let hooks = ScanHooks::default();
+        hooks.cancel.cancel();
+
+        let results = scan_targets(vec![target], 10, Arc::default(), hooks).await;
+        let target = results[0].as_ref().unwrap();
+        assert_eq!(target.unscanned(), 1);
+    }
+
+    /// Check which errors fail a whole target
+    #[test]
+    fn host_errors() {
+        let address = "192.0.2.1:80".parse().unwrap();
+        let connect = |kind| Error::Connect {
+            address,
+            source: std::io::Error::from(kind),
+        };
+        let unroutable = |kind| Error::Unroutable {
+            address,
+            source: std::io::Error::from(kind),
+        };
+        assert!(!fails_host(&connect(ErrorKind::OutOfMemory)));
+        assert!(!fails_host(&connect(ErrorKind::AddrNotAvailable)));
+        assert!(!fails_host(&unroutable(ErrorKind::HostUnreachable)));
+        assert!(fails_host(&connect(ErrorKind::InvalidInput)));
+        assert!(fails_host(&unroutable(ErrorKind::NetworkUnreachable)));
+        assert!(fails_host(&Error::Proxy {
+            proxy: "socks5://proxy:1080".to_string(),
+            reason: "authentication failed".to_string(),
+        }));
+    }
+
+    /// Check that ports with a state are not probed again
+    #[tokio::test]
+    async fn scan_targets_skips_probed_ports() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ports: Arc<[Port]> = Arc::new([Port {
+            service: "unknown",
+            number: listener.local_addr().unwrap().port(),
+        }]);
+        let mut target = Target::new(
+            "localhost".to_string(),
+            "127.0.0.1:0".parse().unwrap(),
+            ports,
+        );
+        target.states.set(0, PortState::Closed);
+
+        let (probes_tx, mut probes_rx) = mpsc::unbounded_channel();
+        let hooks = ScanHooks {
+            probes: Some(probes_tx),
+            ..Default::default()
+        };
+        let results = scan_targets(vec![target], 10, Arc::default(), hooks).await;
+        assert_eq!(
+            results[0].as_ref().unwrap().states.get(0),
+            PortState::Closed
+        );
+        assert!(probes_rx.recv().await.is_none());
+    }
+
+    /// Check that ports are probed in the permuted order of their host
+    #[tokio::test]
+    async fn scan_targets_in_key_order() {
+        let ports: Arc<[Port]> = (1..=16)
+            .map(|number| Port {
+                service: "unknown",
+                number,
+            })
+            .collect();
+        let target = Target::new(
+            "localhost".to_string(),
+            "127.0.0.1:0".parse().unwrap(),
+            ports,
+        );
+
+        let (probes_tx, mut probes_rx) = mpsc::unbounded_channel();
+        let hooks = ScanHooks {
+            probes: Some(probes_tx),
+            seed: 3,
+            ..Default::default()
+        };
+        scan_targets(vec![target], 1, Arc::default(), hooks).await;
+        let mut order = Vec::new();
+        while let Some(probe) = probes_rx.recv().await {
+            order.push(probe.address.port());
+        }
+
+        let key = host_key(3, "127.0.0.1".parse().unwrap());
+        let expected: Vec<u16> = Permutation::new(16, key)
+            .iter()
+            .map(|index| index as u16 + 1)
+            .collect();
+        assert_eq!(order, expected);
+        assert_ne!(order, (1..=16).collect::<Vec<u16>>());
+    }
 }
@@ -0,0 +1,539 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+
+use clap::parser::ValueSource;
+use clap::{Arg, ArgMatches, Command};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+
+use crate::error::Error;
+use crate::http::Url;
+use crate::output::Format;
+use crate::proxy::Proxy;
+use crate::{parse_positive, parse_resolver, DnsArgs, GlobalArgs, ScanArgs};
+
+/// Configuration file read by every user
+const SYSTEM_CONFIG: &str = "/etc/port-scanner/config.toml";
+
+/// Named sets of options, read from toml files:
+///
+/// ```toml
+/// [profile.quick-web]
+/// port = [80, 443, 8080, 8443]
+/// common = 0
+/// concurrency = 100
+/// format = "json"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Config {
+    #[serde(rename = "profile", default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Options of a profile, named like their command line flags
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct Profile {
+    // Output
+    pub format: Option<Format>,
+    pub output: Option<PathBuf>,
+    pub services_file: Option<Vec<PathBuf>>,
+    pub database: Option<PathBuf>,
+    pub policy: Option<PathBuf>,
+
+    // Ports & timing
+    pub port: Option<Vec<u16>>,
+    pub common: Option<usize>,
+    #[serde(default, deserialize_with = "deserialize_positive")]
+    pub concurrency: Option<usize>,
+    pub seed: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_positive")]
+    pub dns_concurrency: Option<usize>,
+    #[serde(default, deserialize_with = "deserialize_positive")]
+    pub monitor: Option<usize>,
+
+    // Techniques
+    pub source_ip: Option<IpAddr>,
+    pub source_port: Option<u16>,
+    pub interface: Option<String>,
+    pub proxy: Option<Vec<Proxy>>,
+    pub ssh_jump: Option<String>,
+    pub ssh_command: Option<PathBuf>,
+    pub ipv4: Option<bool>,
+    pub ipv6: Option<bool>,
+    pub first_address: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_resolver")]
+    pub resolver: Option<SocketAddr>,
+    pub srv: Option<bool>,
+    pub subdomains: Option<PathBuf>,
+    pub no_reverse_dns: Option<bool>,
+
+    // Alerts & exit codes
+    pub events: Option<PathBuf>,
+    pub on_change: Option<String>,
+    pub webhook: Option<Url>,
+    pub fail_on_error: Option<bool>,
+}
+
+/// Deserialize a dns server address, the port defaults to 53
+fn deserialize_resolver<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<SocketAddr>, D::Error> {
+    let address = String::deserialize(deserializer)?;
+    parse_resolver(&address).map(Some).map_err(D::Error::custom)
+}
+
+/// Deserialize an amount of at least 1
+fn deserialize_positive<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<usize>, D::Error> {
+    let amount = usize::deserialize(deserializer)?;
+    parse_positive(&amount.to_string())
+        .map(Some)
+        .map_err(D::Error::custom)
+}
+
+/// System & user configuration files, in the order they override each other
+pub fn config_paths() -> Vec<PathBuf> {
+    let mut paths = vec![PathBuf::from(SYSTEM_CONFIG)];
+    let user = match env::var_os("XDG_CONFIG_HOME") {
+        Some(config) if !config.is_empty() => Some(PathBuf::from(config)),
+        _ => env::var_os("HOME").map(|home| Path::new(&home).join(".config")),
+    };
+    if let Some(user) = user {
+        paths.push(user.join("port-scanner").join("config.toml"));
+    }
+    paths
+}
+
+impl Config {
+    /// Load the existing configuration files of `paths` and `explicit`, which has to exist
+    ///
+    /// Later files override the options of profiles with the same name.
+    pub fn load(paths: &[PathBuf], explicit: Option<&Path>) -> Result<Config, Error> {
+        let mut merged = toml::Table::new();
+        for path in paths.iter() {
+            if path.is_file() {
+                merge(&mut merged, read_table(path)?);
+            }
+        }
+        if let Some(path) = explicit {
+            merge(&mut merged, read_table(path)?);
+        }
+        toml::Value::Table(merged)
+            .try_into()
+            .map_err(|e| Error::Config {
+                path: explicit
+                    .map(Path::to_owned)
+                    .or_else(|| paths.last().cloned())
+                    .unwrap_or_default(),
+                reason: e.message().to_string(),
+            })
+    }
+
+    /// Get a profile by name
+    pub fn profile(&self, name: &str) -> Result<&Profile, Error> {
+        self.profiles
+            .get(name)
+            .ok_or_else(|| Error::UnknownProfile {
+                name: name.to_string(),
+            })
+    }
+}
+
+/// Read a configuration file as a toml table
+fn read_table(path: &Path) -> Result<toml::Table, Error> {
+    let error = |reason: String| Error::Config {
+        path: path.to_owned(),
+        reason,
+    };
+    let content = fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+    let table: toml::Table =
+        toml::from_str(&content).map_err(|e| error(e.message().to_string()))?;
+    // Check the file on its own, so errors name it
+    toml::Value::Table(table.clone())
+        .try_into::<Config>()
+        .map_err(|e| error(e.message().to_string()))?;
+    Ok(table)
+}
+
+/// Merge the tables of `other` into `table`, replacing other values
+fn merge(table: &mut toml::Table, other: toml::Table) {
+    for (key, value) in other {
+        match (table.get_mut(&key), value) {
+            (Some(toml::Value::Table(table)), toml::Value::Table(other)) => merge(table, other),
+            (_, value) => {
+                table.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Check if any of the options was given on the command line
+fn given(matches: &ArgMatches, ids: &[&str]) -> bool {
+    ids.iter()
+        .any(|id| matches.value_source(id) == Some(ValueSource::CommandLine))
+}
+
+/// Options given on the command line & the ids of the ones set from a profile
+struct Applied<'a> {
+    matches: &'a ArgMatches,
+    ids: Vec<&'static str>,
+}
+
+impl Applied<'_> {
+    /// Set an option to the profile value, unless it or a conflicting option was given
+    ///
+    /// The first of `ids` is the option's own.
+    fn set<T: Clone>(&mut self, option: &mut T, value: &Option<T>, ids: &[&'static str]) {
+        if let Some(value) = value {
+            if !given(self.matches, ids) {
+                *option = value.clone();
+                self.ids.push(ids[0]);
+            }
+        }
+    }
+
+    /// Set a flag to the profile value, unless it or a conflicting option was given
+    ///
+    /// Only enabled flags count as used.
+    fn set_flag(&mut self, option: &mut bool, value: &Option<bool>, ids: &[&'static str]) {
+        if let Some(value) = value {
+            if !given(self.matches, ids) {
+                *option = *value;
+                if *value {
+                    self.ids.push(ids[0]);
+                }
+            }
+        }
+    }
+
+    /// Set an optional option to the profile value, unless it or a conflicting option was given
+    fn set_some<T: Clone>(
+        &mut self,
+        option: &mut Option<T>,
+        value: &Option<T>,
+        ids: &[&'static str],
+    ) {
+        self.set(option, &value.clone().map(Some), ids);
+    }
+
+    /// Check if an option was given or set from the profile
+    fn is_used(&self, id: &str) -> bool {
+        self.ids.contains(&id) || given(self.matches, &[id])
+    }
+
+    /// Check the used options against the conflicts of `command` & [`REQUIRES`],
+    /// which clap only checks for given options
+    fn check(&self, command: &Command) -> Result<(), String> {
+        let arg = |id: &str| {
+            command
+                .get_arguments()
+                .find(|arg| arg.get_id() == id)
+                .expect("Profile options are arguments")
+        };
+        for used in command
+            .get_arguments()
+            .filter(|arg| self.is_used(arg.get_id().as_str()))
+        {
+            if let Some(other) = command
+                .get_arg_conflicts_with(used)
+                .into_iter()
+                .find(|other| self.is_used(other.get_id().as_str()))
+            {
+                return Err(format!(
+                    "{} cannot be used with {}",
+                    flag(used),
+                    flag(other)
+                ));
+            }
+        }
+        for (id, required) in REQUIRES {
+            if self.is_used(id) && !self.is_used(required) {
+                return Err(format!(
+                    "{} requires {}",
+                    flag(arg(id)),
+                    flag(arg(required))
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Options of profiles that require another, as declared for clap
+const REQUIRES: [(&str, &str); 3] = [
+    ("events", "monitor"),
+    ("on_change", "monitor"),
+    ("webhook", "monitor"),
+];
+
+/// Flag of an option for messages
+fn flag(arg: &Arg) -> String {
+    match (arg.get_long(), arg.get_short()) {
+        (Some(long), _) => format!("--{}", long),
+        (None, Some(short)) => format!("-{}", short),
+        (None, None) => arg.get_id().to_string(),
+    }
+}
+
+impl Profile {
+    /// Use the profile for the options shared by every command not given in `matches`
+    pub fn apply_global(&self, args: &mut GlobalArgs, matches: &ArgMatches) {
+        let mut applied = Applied {
+            matches,
+            ids: Vec::new(),
+        };
+        applied.set(&mut args.format, &self.format, &["format"]);
+        applied.set_some(&mut args.output, &self.output, &["output"]);
+        applied.set(
+            &mut args.services_file,
+            &self.services_file,
+            &["services_file"],
+        );
+    }
+
+    /// Use the profile for the dns options not given in `matches` of `command`
+    pub fn apply_dns(
+        &self,
+        args: &mut DnsArgs,
+        command: &Command,
+        matches: &ArgMatches,
+    ) -> Result<(), String> {
+        let mut applied = Applied {
+            matches,
+            ids: Vec::new(),
+        };
+        self.set_dns(args, &mut applied);
+        applied.check(command)
+    }
+
+    /// Set the dns options of the profile
+    fn set_dns(&self, args: &mut DnsArgs, applied: &mut Applied) {
+        applied.set_flag(&mut args.ipv4, &self.ipv4, &["ipv4", "ipv6"]);
+        applied.set_flag(&mut args.ipv6, &self.ipv6, &["ipv6", "ipv4"]);
+        applied.set_flag(
+            &mut args.first_address,
+            &self.first_address,
+            &["first_address"],
+        );
+        applied.set_some(&mut args.resolver, &self.resolver, &["resolver"]);
+        applied.set_flag(&mut args.srv, &self.srv, &["srv"]);
+        applied.set_some(&mut args.subdomains, &self.subdomains, &["subdomains"]);
+        applied.set(
+            &mut args.dns_concurrency,
+            &self.dns_concurrency,
+            &["dns_concurrency"],
+        );
+        applied.set_flag(
+            &mut args.no_reverse_dns,
+            &self.no_reverse_dns,
+            &["no_reverse_dns"],
+        );
+    }
+
+    /// Use the profile for the scan options not given in `matches` of `command`
+    ///
+    /// Fails when options of the profile conflict with each other or given ones.
+    pub fn apply_scan(
+        &self,
+        args: &mut ScanArgs,
+        command: &Command,
+        matches: &ArgMatches,
+    ) -> Result<(), String> {
+        let mut applied = Applied {
+            matches,
+            ids: Vec::new(),
+        };
+        applied.set_some(&mut args.port, &self.port, &["port"]);
+        applied.set_some(&mut args.common, &self.common, &["common"]);
+        applied.set(&mut args.concurrency, &self.concurrency, &["concurrency"]);
+        applied.set(&mut args.seed, &self.seed, &["seed"]);
+        applied.set_some(&mut args.source_ip, &self.source_ip, &["source_ip"]);
+        applied.set_some(&mut args.source_port, &self.source_port, &["source_port"]);
+        applied.set_some(&mut args.interface, &self.interface, &["interface"]);
+        applied.set(&mut args.proxy, &self.proxy, &["proxy", "ssh_jump"]);
+        applied.set_some(&mut args.ssh_jump, &self.ssh_jump, &["ssh_jump", "proxy"]);
+        applied.set(&mut args.ssh_command, &self.ssh_command, &["ssh_command"]);
+        self.set_dns(&mut args.dns, &mut applied);
+        applied.set_some(&mut args.database, &self.database, &["database"]);
+        applied.set_some(&mut args.policy, &self.policy, &["policy"]);
+        applied.set_some(&mut args.monitor, &self.monitor, &["monitor", "checkpoint"]);
+        applied.set_some(&mut args.events, &self.events, &["events"]);
+        applied.set_some(&mut args.on_change, &self.on_change, &["on_change"]);
+        applied.set_some(&mut args.webhook, &self.webhook, &["webhook"]);
+        applied.set_flag(
+            &mut args.fail_on_error,
+            &self.fail_on_error,
+            &["fail_on_error"],
+        );
+        applied.check(command)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cli, Command};
+    use clap::{CommandFactory, FromArgMatches};
+
+    /// Write a configuration file to a temporary path
+    fn config_file(name: &str, content: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("{}-{}.toml", name, std::process::id()));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    /// Check that later files override single options of a profile
+    #[test]
+    fn load_merged_profiles() {
+        let system = config_file(
+            "system",
+            "[profile.web]\nport = [80, 443]\nconcurrency = 50\n\n\
+             [profile.full]\ncommon = 5000\n",
+        );
+        let user = config_file(
+            "user",
+            "[profile.web]\nconcurrency = 10\nformat = \"json\"\n",
+        );
+        let missing = env::temp_dir().join("missing-config.toml");
+
+        let config = Config::load(&[system.clone(), missing], Some(&user)).unwrap();
+        let web = config.profile("web").unwrap();
+        assert_eq!(web.port, Some(vec![80, 443]));
+        assert_eq!(web.concurrency, Some(10));
+        assert_eq!(web.format, Some(Format::Json));
+        assert_eq!(config.profile("full").unwrap().common, Some(5000));
+        assert!(config.profile("audit").is_err());
+
+        let zero = config_file("zero", "[profile.web]\nconcurrency = 0\n");
+        assert!(Config::load(&[], Some(&zero)).is_err());
+        let no_interval = config_file("no-interval", "[profile.web]\nmonitor = 0\n");
+        assert!(Config::load(&[], Some(&no_interval)).is_err());
+
+        let invalid = config_file("invalid", "[profile.web]\nconcurency = 10\n");
+        let error = Config::load(&[system.clone(), invalid.clone()], None).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with(&invalid.display().to_string()));
+
+        for path in [system, user, zero, no_interval, invalid] {
+            fs::remove_file(path).unwrap();
+        }
+    }
+
+    /// Check that command line flags override profile values
+    #[test]
+    fn flags_override_profile() {
+        let profile: Profile = toml::from_str(
+            "port = [80]\nconcurrency = 50\nresolver = \"192.0.2.53\"\n\
+             ssh-jump = \"jump\"\nformat = \"json\"\nsrv = true\n",
+        )
+        .unwrap();
+        let matches = Cli::command()
+            .try_get_matches_from([
+                "port-scanner",
+                "scan",
+                "host",
+                "--concurrency",
+                "10",
+                "--proxy",
+                "socks5://proxy",
+                "--format",
+                "text",
+            ])
+            .unwrap();
+        let Cli {
+            command: Command::Scan(mut args),
+            mut global,
+        } = Cli::from_arg_matches(&matches).unwrap()
+        else {
+            panic!("Expected scan");
+        };
+        let (_, matches) = matches.subcommand().unwrap();
+        profile.apply_global(&mut global, matches);
+        let command = Cli::command();
+        let scan = command.find_subcommand("scan").unwrap();
+        profile.apply_scan(&mut args, scan, matches).unwrap();
+
+        assert_eq!(global.format, Format::Text);
+        assert_eq!(args.port, Some(vec![80]));
+        assert_eq!(args.concurrency, 10);
+        assert_eq!(args.dns.resolver, Some("192.0.2.53:53".parse().unwrap()));
+        assert!(args.dns.srv);
+        assert_eq!(args.ssh_jump, None);
+        assert_eq!(args.proxy.len(), 1);
+
+        for flag in ["--concurrency", "--monitor"] {
+            let zero = ["port-scanner", "scan", "host", flag, "0"];
+            assert!(Cli::command().try_get_matches_from(zero).is_err());
+        }
+    }
+
+    /// Apply a profile to scan arguments
+    fn apply_scan(profile: &str, flags: &[&str]) -> Result<Box<ScanArgs>, String> {
+        let profile: Profile = toml::from_str(profile).unwrap();
+        let command = Cli::command();
+        let matches = command
+            .clone()
+            .try_get_matches_from(["port-scanner", "scan", "host"].iter().chain(flags))
+            .unwrap();
+        let Cli {
+            command: Command::Scan(mut args),
+            ..
+        } = Cli::from_arg_matches(&matches).unwrap()
+        else {
+            panic!("Expected scan");
+        };
+        let (_, matches) = matches.subcommand().unwrap();
+        let scan = command.find_subcommand("scan").unwrap();
+        profile.apply_scan(&mut args, scan, matches)?;
+        Ok(args)
+    }
+
+    /// Check that profiles cannot combine options clap would reject
+    #[test]
+    fn profile_conflicts() {
+        let error = apply_scan("events = \"events.json\"\n", &[]).unwrap_err();
+        assert_eq!(error, "--events requires --monitor");
+        assert!(apply_scan("events = \"events.json\"\n", &["--monitor", "60"]).is_ok());
+        assert!(apply_scan("ipv4 = true\nipv6 = true\n", &[]).is_err());
+        assert!(apply_scan("ipv4 = false\nipv6 = true\n", &[]).is_ok());
+        assert!(apply_scan("proxy = [\"socks5://proxy\"]\nssh-jump = \"jump\"\n", &[]).is_err());
+        assert!(apply_scan("policy = \"policy.toml\"\n", &["--monitor", "60"]).is_err());
+        assert!(apply_scan("ssh-jump = \"jump\"\n", &["--proxy", "socks5://proxy"]).is_ok());
+        assert!(apply_scan("policy = \"policy.toml\"\n", &["--shard", "1/2"]).is_err());
+        let sharded = [
+            "port-scanner",
+            "scan",
+            "host",
+            "--shard",
+            "1/2",
+            "--policy",
+            "p.toml",
+        ];
+        assert!(Cli::command().try_get_matches_from(sharded).is_err());
+
+        // The requirements are those of clap
+        let command = Cli::command();
+        let scan = command.find_subcommand("scan").unwrap();
+        let long = |id: &str| {
+            let arg = scan.get_arguments().find(|arg| arg.get_id() == id);
+            format!("--{}", arg.unwrap().get_long().unwrap())
+        };
+        for (id, required) in REQUIRES {
+            let flags = ["port-scanner", "scan", "host", &long(id), "http://x"];
+            let error = Cli::command().try_get_matches_from(flags).unwrap_err();
+            assert_eq!(
+                error.kind(),
+                clap::error::ErrorKind::MissingRequiredArgument
+            );
+            let required = long(required);
+            let flags = [&flags[..], &[&required, "60"]].concat();
+            assert!(Cli::command().try_get_matches_from(flags).is_ok());
+        }
+    }
+}
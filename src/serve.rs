@@ -0,0 +1,590 @@
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, watch, Semaphore};
+use tokio::time::{sleep, Duration};
+use tokio_util::sync::CancellationToken;
+
+use crate::address::TargetSpec;
+use crate::common_ports::{Protocol, ServiceRegistry};
+use crate::connect::Connector;
+use crate::discovery::{discover_targets, Discovery, DiscoveryOptions};
+use crate::dns::Resolver;
+use crate::http::{read_request, write_response, write_stream_head, Request};
+use crate::output::Report;
+use crate::port::{dedup_targets, scan_targets, Port, PortState, ProbeResult, ScanHooks};
+
+/// Minimum time between two progress events of a job
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Maximum amount of common or listed ports of a job, every tcp port
+const MAX_PORTS: usize = 65535;
+
+/// A scan job as submitted to `POST /scans`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JobSpec {
+    /// Host names, addresses or networks
+    pub targets: Vec<String>,
+    /// Ports to scan besides the common ports
+    #[serde(default)]
+    pub ports: Vec<u16>,
+    /// Amount of common ports to scan, by open-frequency
+    #[serde(default = "default_common")]
+    pub common: usize,
+    /// Maximum amount of ports probed at the same time, at most the server's
+    pub concurrency: Option<usize>,
+}
+
+impl JobSpec {
+    /// Check the job against the limits of the server
+    fn check(&self, limits: &Limits) -> Result<(), String> {
+        if self.targets.is_empty() {
+            return Err("no targets".to_string());
+        }
+        let addresses: u128 = self
+            .targets
+            .iter()
+            .map(|target| target.parse().map_or(1, |spec: TargetSpec| spec.size()))
+            .sum();
+        if addresses > limits.targets as u128 {
+            return Err(format!(
+                "at most {} target addresses can be scanned",
+                limits.targets
+            ));
+        }
+        if let Some(concurrency) = self.concurrency {
+            if concurrency == 0 || concurrency > limits.concurrency {
+                return Err(format!(
+                    "concurrency has to be between 1 and {}",
+                    limits.concurrency
+                ));
+            }
+        }
+        if self.common > MAX_PORTS || self.ports.len() > MAX_PORTS {
+            return Err(format!("at most {} ports can be scanned", MAX_PORTS));
+        }
+        if self.ports.contains(&0) {
+            return Err("port 0 cannot be scanned".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Common ports scanned when a job does not choose, like on the command line
+fn default_common() -> usize {
+    1000
+}
+
+/// State of a scan job
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobState {
+    /// Waiting for a free job slot
+    Queued,
+    Running,
+    Done,
+    /// Cancelled while queued or running, results are partial
+    Cancelled,
+}
+
+impl JobState {
+    /// Check if the job ended
+    fn is_finished(self) -> bool {
+        matches!(self, JobState::Done | JobState::Cancelled)
+    }
+}
+
+/// Status & progress of a job, also sent as progress event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobStatus {
+    pub id: u64,
+    pub state: JobState,
+    /// Ports to probe, known once the targets are resolved
+    pub total: usize,
+    pub probed: usize,
+    pub open: usize,
+}
+
+/// A submitted scan job
+struct Job {
+    spec: JobSpec,
+    cancel: CancellationToken,
+    status: watch::Sender<JobStatus>,
+    report: Mutex<Option<Report>>,
+}
+
+/// Limits of the server
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    /// Jobs running at the same time
+    pub jobs: usize,
+    /// Jobs queued or running, more are refused
+    pub queued: usize,
+    /// Maximum probes in flight per job
+    pub concurrency: usize,
+    /// Maximum addresses per job, networks count with all their addresses
+    pub targets: usize,
+    /// Finished jobs kept for their results, older ones are removed
+    pub finished: usize,
+}
+
+/// Scans submitted over http, run by a job queue
+pub struct Server {
+    registry: ServiceRegistry,
+    connector: Arc<Connector>,
+    resolver: Resolver,
+    discovery: DiscoveryOptions,
+    reverse_dns: bool,
+    limits: Limits,
+    slots: Semaphore,
+    jobs: Mutex<BTreeMap<u64, Arc<Job>>>,
+    next_id: AtomicU64,
+}
+
+impl Server {
+    pub fn new(
+        registry: ServiceRegistry,
+        connector: Arc<Connector>,
+        resolver: Resolver,
+        discovery: DiscoveryOptions,
+        reverse_dns: bool,
+        limits: Limits,
+    ) -> Server {
+        Server {
+            registry,
+            connector,
+            resolver,
+            discovery,
+            reverse_dns,
+            limits,
+            slots: Semaphore::new(limits.jobs),
+            jobs: Mutex::new(BTreeMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    /// Get a job by id
+    fn job(&self, id: u64) -> Option<Arc<Job>> {
+        self.jobs.lock().expect("Jobs poisoned").get(&id).cloned()
+    }
+
+    /// Queue a job, `None` when the queue is full
+    fn submit(self: &Arc<Self>, spec: JobSpec, cancel: CancellationToken) -> Option<JobStatus> {
+        let mut jobs = self.jobs.lock().expect("Jobs poisoned");
+        let finished: Vec<u64> = jobs
+            .iter()
+            .filter(|(_, job)| job.status.borrow().state.is_finished())
+            .map(|(&id, _)| id)
+            .collect();
+        let expired = finished.len().saturating_sub(self.limits.finished);
+        for id in finished.into_iter().take(expired) {
+            jobs.remove(&id);
+        }
+        let pending = jobs
+            .values()
+            .filter(|job| !job.status.borrow().state.is_finished())
+            .count();
+        if pending >= self.limits.queued {
+            return None;
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let status = JobStatus {
+            id,
+            state: JobState::Queued,
+            total: 0,
+            probed: 0,
+            open: 0,
+        };
+        let job = Arc::new(Job {
+            spec,
+            cancel,
+            status: watch::Sender::new(status.clone()),
+            report: Mutex::new(None),
+        });
+        jobs.insert(id, job.clone());
+        tokio::spawn(self.clone().run_job(job));
+        Some(status)
+    }
+
+    /// Wait for a job slot & scan the targets of a job
+    async fn run_job(self: Arc<Self>, job: Arc<Job>) {
+        let _slot = tokio::select! {
+            slot = self.slots.acquire() => slot.expect("Job slots are never closed"),
+            _ = job.cancel.cancelled() => {
+                *job.report.lock().expect("Report poisoned") = Some(Report::new(&[], false));
+                job.status.send_modify(|status| status.state = JobState::Cancelled);
+                return;
+            }
+        };
+        job.status
+            .send_modify(|status| status.state = JobState::Running);
+
+        // Ports & targets, names are resolved again for every job
+        let spec = &job.spec;
+        self.resolver.clear_cache();
+        let mut ports = self.registry.get_common_ports(Protocol::Tcp, spec.common);
+        for &number in spec.ports.iter() {
+            if !ports.iter().any(|port| port.number == number) {
+                ports.push(Port {
+                    service: self
+                        .registry
+                        .service_name(number, Protocol::Tcp)
+                        .unwrap_or("unknown"),
+                    number,
+                });
+            }
+        }
+        let Discovery { targets, failures } =
+            discover_targets(&spec.targets, ports.into(), &self.resolver, &self.discovery).await;
+        let targets = dedup_targets(targets);
+        let total = targets.iter().map(|target| target.ports.len()).sum();
+        job.status.send_modify(|status| status.total = total);
+
+        // Count probes for progress events
+        let (probes_tx, mut probes_rx) = mpsc::unbounded_channel::<ProbeResult>();
+        let progress_job = job.clone();
+        let progress = tokio::spawn(async move {
+            while let Some(probe) = probes_rx.recv().await {
+                progress_job.status.send_modify(|status| {
+                    status.probed += 1;
+                    if probe.state == PortState::Open {
+                        status.open += 1;
+                    }
+                });
+            }
+        });
+
+        // Scan, cancelling keeps the results so far
+        let hooks = ScanHooks {
+            cancel: job.cancel.clone(),
+            probes: Some(probes_tx),
+            ..Default::default()
+        };
+        let concurrency = spec.concurrency.unwrap_or(self.limits.concurrency);
+        let mut results = scan_targets(targets, concurrency, self.connector.clone(), hooks).await;
+        progress.await.expect("Progress counter panicked");
+        let complete = !job.cancel.is_cancelled();
+        if self.reverse_dns && complete {
+            self.resolver
+                .reverse_lookup_targets(
+                    results.iter_mut().filter_map(|res| res.as_mut().ok()),
+                    self.discovery.concurrency,
+                )
+                .await;
+        }
+        results.extend(failures.into_iter().map(Err));
+
+        *job.report.lock().expect("Report poisoned") = Some(Report::new(&results, complete));
+        let state = if complete {
+            JobState::Done
+        } else {
+            JobState::Cancelled
+        };
+        job.status.send_modify(|status| status.state = state);
+    }
+}
+
+/// Serve the scan api on `listener` until cancelled, which also cancels all jobs
+///
+/// - `POST /scans` queues a job, answering with its status
+/// - `GET /scans` lists the status of all jobs
+/// - `GET /scans/ID` gets the status of a job
+/// - `GET /scans/ID/events` streams the status on progress, as json lines
+/// - `GET /scans/ID/results` gets the results of a finished job, like `--format json`
+/// - `DELETE /scans/ID` cancels a job
+pub async fn serve(
+    listener: TcpListener,
+    server: Arc<Server>,
+    cancel: CancellationToken,
+) -> io::Result<()> {
+    loop {
+        let (mut client, _) = tokio::select! {
+            accepted = listener.accept() => accepted?,
+            _ = cancel.cancelled() => return Ok(()),
+        };
+        let server = server.clone();
+        let cancel = cancel.clone();
+        tokio::spawn(async move {
+            let response = match read_request(&mut client).await {
+                Ok(request) => handle(&server, &cancel, request, &mut client).await,
+                Err(e) => respond(&mut client, 400, &error_body(e)).await,
+            };
+            if let Err(e) = response {
+                eprintln!("Responding failed: {}", e);
+            }
+        });
+    }
+}
+
+/// Json body of an error
+fn error_body(error: impl ToString) -> serde_json::Value {
+    serde_json::json!({ "error": error.to_string() })
+}
+
+/// Write a json response
+async fn respond(client: &mut TcpStream, status: u16, body: &impl Serialize) -> io::Result<()> {
+    let mut body = serde_json::to_vec_pretty(body).expect("Responses serialize");
+    body.push(b'\n');
+    write_response(client, status, "application/json", &body).await
+}
+
+/// Route a request
+async fn handle(
+    server: &Arc<Server>,
+    cancel: &CancellationToken,
+    request: Request,
+    client: &mut TcpStream,
+) -> io::Result<()> {
+    let segments: Vec<&str> = request
+        .path
+        .split('?')
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect();
+    let job = match segments.get(1).map(|id| id.parse::<u64>()) {
+        Some(Ok(id)) => match server.job(id) {
+            Some(job) => Some(job),
+            None => return respond(client, 404, &error_body("unknown job")).await,
+        },
+        Some(Err(_)) => return respond(client, 404, &error_body("unknown job")).await,
+        None => None,
+    };
+
+    match (request.method.as_str(), &segments[..], job) {
+        ("POST", ["scans"], None) => match serde_json::from_slice::<JobSpec>(&request.body) {
+            Ok(spec) => match spec.check(&server.limits) {
+                Ok(()) => match server.submit(spec, cancel.child_token()) {
+                    Some(status) => respond(client, 202, &status).await,
+                    None => respond(client, 503, &error_body("job queue is full")).await,
+                },
+                Err(reason) => respond(client, 400, &error_body(reason)).await,
+            },
+            Err(e) => respond(client, 400, &error_body(e)).await,
+        },
+        ("GET", ["scans"], None) => {
+            let statuses: Vec<JobStatus> = server
+                .jobs
+                .lock()
+                .expect("Jobs poisoned")
+                .values()
+                .map(|job| job.status.borrow().clone())
+                .collect();
+            respond(client, 200, &statuses).await
+        }
+        ("GET", ["scans", _], Some(job)) => {
+            let status = job.status.borrow().clone();
+            respond(client, 200, &status).await
+        }
+        ("GET", ["scans", _, "events"], Some(job)) => stream_events(client, &job).await,
+        ("GET", ["scans", _, "results"], Some(job)) => {
+            let report = job.report.lock().expect("Report poisoned").clone();
+            match report {
+                Some(report) => respond(client, 200, &report).await,
+                None => respond(client, 409, &error_body("job is not finished")).await,
+            }
+        }
+        ("DELETE", ["scans", _], Some(job)) => {
+            job.cancel.cancel();
+            let status = job.status.borrow().clone();
+            respond(client, 202, &status).await
+        }
+        (_, ["scans"], None) | (_, ["scans", _], Some(_)) => {
+            respond(client, 405, &error_body("method not allowed")).await
+        }
+        _ => respond(client, 404, &error_body("not found")).await,
+    }
+}
+
+/// Stream the status of a job as json lines until it is finished
+async fn stream_events(client: &mut TcpStream, job: &Job) -> io::Result<()> {
+    write_stream_head(client, 200, "application/x-ndjson").await?;
+    let mut status = job.status.subscribe();
+    loop {
+        let current = status.borrow_and_update().clone();
+        let mut line = serde_json::to_vec(&current).expect("Statuses serialize");
+        line.push(b'\n');
+        client.write_all(&line).await?;
+        if current.state.is_finished() || status.changed().await.is_err() {
+            return client.shutdown().await;
+        }
+        sleep(PROGRESS_INTERVAL).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncReadExt;
+
+    /// Send a request to the server, returning the status & body
+    async fn request(
+        address: std::net::SocketAddr,
+        method: &str,
+        path: &str,
+        body: &str,
+    ) -> (u16, String) {
+        let mut stream = TcpStream::connect(address).await.unwrap();
+        let head = format!(
+            "{} {} HTTP/1.1\r\nHost: test\r\nContent-Length: {}\r\n\r\n",
+            method,
+            path,
+            body.len()
+        );
+        stream.write_all(head.as_bytes()).await.unwrap();
+        stream.write_all(body.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+        (status, body.to_string())
+    }
+
+    /// Spawn a server on localhost
+    async fn spawn_server(limits: Limits) -> (std::net::SocketAddr, CancellationToken) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = Arc::new(Server::new(
+            ServiceRegistry::embedded(),
+            Arc::new(Connector::default()),
+            Resolver::new(crate::dns::Family::Any, true, None),
+            DiscoveryOptions::default(),
+            false,
+            limits,
+        ));
+        let cancel = CancellationToken::new();
+        tokio::spawn(serve(listener, server, cancel.clone()));
+        (address, cancel)
+    }
+
+    /// Check that a submitted job runs, streams progress & returns its results
+    #[tokio::test]
+    async fn submit_and_fetch_results() {
+        let open = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = open.local_addr().unwrap().port();
+        let limits = Limits {
+            jobs: 1,
+            queued: 4,
+            concurrency: 10,
+            targets: 256,
+            finished: 10,
+        };
+        let (address, cancel) = spawn_server(limits).await;
+
+        let spec = format!(
+            r#"{{"targets": ["127.0.0.1"], "ports": [{}], "common": 5}}"#,
+            port
+        );
+        let (code, body) = request(address, "POST", "/scans", &spec).await;
+        assert_eq!(code, 202);
+        let status: JobStatus = serde_json::from_str(&body).unwrap();
+
+        let (code, events) =
+            request(address, "GET", &format!("/scans/{}/events", status.id), "").await;
+        assert_eq!(code, 200);
+        let last: JobStatus = serde_json::from_str(events.lines().last().unwrap()).unwrap();
+        assert_eq!(last.state, JobState::Done);
+        assert_eq!((last.total, last.probed, last.open), (6, 6, 1));
+
+        let path = format!("/scans/{}/results", status.id);
+        let (code, body) = request(address, "GET", &path, "").await;
+        assert_eq!(code, 200);
+        let report: Report = serde_json::from_str(&body).unwrap();
+        assert!(report.complete);
+        assert_eq!(report.targets[0].open_ports[0].number, port);
+
+        let (code, _) = request(address, "GET", "/scans/99", "").await;
+        assert_eq!(code, 404);
+        for invalid in [
+            r#"{"target": []}"#,
+            r#"{"targets": []}"#,
+            r#"{"targets": ["127.0.0.1"], "concurrency": 0}"#,
+            r#"{"targets": ["127.0.0.1"], "concurrency": 11}"#,
+            r#"{"targets": ["127.0.0.1"], "common": 100000}"#,
+            r#"{"targets": ["127.0.0.1"], "ports": [0]}"#,
+            r#"{"targets": ["127.0.0.1", "10.0.0.0/24"]}"#,
+        ] {
+            let (code, _) = request(address, "POST", "/scans", invalid).await;
+            assert_eq!(code, 400, "{}", invalid);
+        }
+        cancel.cancel();
+    }
+
+    /// Check that jobs beyond the queue limit are refused & queued jobs can be cancelled
+    #[tokio::test]
+    async fn queue_limits() {
+        // Without job slots every job stays queued
+        let limits = Limits {
+            jobs: 0,
+            queued: 2,
+            concurrency: 1,
+            targets: 256,
+            finished: 10,
+        };
+        let (address, cancel) = spawn_server(limits).await;
+
+        let spec = r#"{"targets": ["127.0.0.1"]}"#;
+        for _ in 0..2 {
+            let (code, _) = request(address, "POST", "/scans", spec).await;
+            assert_eq!(code, 202);
+        }
+        let (code, _) = request(address, "POST", "/scans", spec).await;
+        assert_eq!(code, 503);
+
+        let (code, body) = request(address, "DELETE", "/scans/2", "").await;
+        assert_eq!(code, 202);
+        assert_eq!(
+            serde_json::from_str::<JobStatus>(&body).unwrap().state,
+            JobState::Queued
+        );
+        let (_, events) = request(address, "GET", "/scans/2/events", "").await;
+        assert!(events.lines().last().unwrap().contains("\"cancelled\""));
+
+        let (code, body) = request(address, "GET", "/scans", "").await;
+        assert_eq!(code, 200);
+        let statuses: Vec<JobStatus> = serde_json::from_str(&body).unwrap();
+        assert_eq!(statuses[0].state, JobState::Queued);
+        let (code, _) = request(address, "GET", "/scans/1/results", "").await;
+        assert_eq!(code, 409);
+        let (code, _) = request(address, "POST", "/scans", spec).await;
+        assert_eq!(code, 202);
+        cancel.cancel();
+    }
+
+    /// Check that only the latest finished jobs are kept
+    #[tokio::test]
+    async fn remove_finished_jobs() {
+        let limits = Limits {
+            jobs: 1,
+            queued: 4,
+            concurrency: 10,
+            targets: 256,
+            finished: 2,
+        };
+        let (address, cancel) = spawn_server(limits).await;
+
+        let spec = r#"{"targets": ["127.0.0.1"], "ports": [1], "common": 0}"#;
+        for id in 1..=4 {
+            let (code, _) = request(address, "POST", "/scans", spec).await;
+            assert_eq!(code, 202);
+            let (_, events) = request(address, "GET", &format!("/scans/{}/events", id), "").await;
+            assert!(events.lines().last().unwrap().contains("\"done\""));
+        }
+
+        let (_, body) = request(address, "GET", "/scans", "").await;
+        let statuses: Vec<JobStatus> = serde_json::from_str(&body).unwrap();
+        let ids: Vec<u64> = statuses.iter().map(|status| status.id).collect();
+        assert_eq!(ids, vec![2, 3, 4]);
+        let (code, _) = request(address, "GET", "/scans/1/results", "").await;
+        assert_eq!(code, 404);
+        cancel.cancel();
+    }
+}
@@ -0,0 +1,149 @@
+//! Parser for nmap-services formatted files
+//!
+//! Shared by the build script, which generates the embedded service table,
+//! and the service registry, which loads custom files at runtime.
+
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Transport protocol of a service
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+    Sctp,
+}
+
+impl FromStr for Protocol {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tcp" => Ok(Protocol::Tcp),
+            "udp" => Ok(Protocol::Udp),
+            "sctp" => Ok(Protocol::Sctp),
+            _ => Err(format!("unknown protocol '{}'", s)),
+        }
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Protocol::Tcp => "tcp",
+            Protocol::Udp => "udp",
+            Protocol::Sctp => "sctp",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Defines a service with name, port number, protocol & open-frequency
+#[derive(Debug, Clone, PartialEq)]
+pub struct Service {
+    pub name: Cow<'static, str>,
+    pub number: u16,
+    pub protocol: Protocol,
+    pub frequency: f64,
+}
+
+/// Error in a line of a services file
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse a single line of an nmap-services file
+///
+/// Returns `None` for empty lines & comments
+fn parse_line(line: &str) -> Option<Result<Service, String>> {
+    let line = match line.find('#') {
+        Some(index) => &line[..index],
+        None => line,
+    };
+    let mut fields = line.split_whitespace();
+    let name = fields.next()?;
+
+    Some(parse_service(name, fields))
+}
+
+/// Parse the port/protocol & optional open-frequency fields of a service
+fn parse_service<'a>(
+    name: &str,
+    mut fields: impl Iterator<Item = &'a str>,
+) -> Result<Service, String> {
+    let port = fields.next().ok_or("missing port/protocol")?;
+    let (number, protocol) = port
+        .split_once('/')
+        .ok_or(format!("expected <port>/<protocol>, got '{}'", port))?;
+    let number = number
+        .parse()
+        .map_err(|_| format!("invalid port number '{}'", number))?;
+    let protocol = protocol.parse()?;
+    let frequency = match fields.next() {
+        Some(freq) => freq
+            .parse()
+            .ok()
+            .filter(|freq: &f64| freq.is_finite())
+            .ok_or(format!("invalid open-frequency '{}'", freq))?,
+        None => 0.0,
+    };
+    Ok(Service {
+        name: Cow::Owned(name.to_string()),
+        number,
+        protocol,
+        frequency,
+    })
+}
+
+/// Parse nmap-services formatted text
+///
+/// Services are sorted by protocol & port number, when a port occurs
+/// multiple times the last entry wins.
+pub fn parse(input: &str) -> Result<Vec<Service>, ParseError> {
+    let mut services = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        match parse_line(line) {
+            Some(Ok(service)) => services.push(service),
+            Some(Err(message)) => {
+                return Err(ParseError {
+                    line: index + 1,
+                    message,
+                })
+            }
+            None => (),
+        }
+    }
+    Ok(normalize(services))
+}
+
+/// Sort services by protocol & port number, keeping the last of duplicate entries
+pub fn normalize(mut services: Vec<Service>) -> Vec<Service> {
+    services.reverse();
+    services.sort_by_key(|service| (service.protocol, service.number));
+    services.dedup_by_key(|service| (service.protocol, service.number));
+    services
+}
+
+/// Indices of services by descending open-frequency, ties by ascending port number
+pub fn frequency_order(services: &[Service]) -> Vec<u32> {
+    let mut order: Vec<u32> = (0..services.len() as u32).collect();
+    order.sort_by(|&a, &b| {
+        let (a, b) = (&services[a as usize], &services[b as usize]);
+        b.frequency
+            .total_cmp(&a.frequency)
+            .then(a.number.cmp(&b.number))
+    });
+    order
+}
@@ -0,0 +1,237 @@
+use crate::port::Target;
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// One of `count` disjoint slices of the (host, port) space, `index` counts from 1
+///
+/// Machines scanning the same targets with the same seed & count each probe a
+/// different slice, so together they probe every port exactly once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Shard {
+    pub index: u64,
+    pub count: u64,
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    /// Parse a shard as `index/count`, e.g. `2/4`
+    fn from_str(s: &str) -> Result<Shard, String> {
+        let (index, count) = s
+            .split_once('/')
+            .ok_or_else(|| "expected index/count, e.g. 1/4".to_string())?;
+        let index: u64 = index.parse().map_err(|e| format!("invalid index: {}", e))?;
+        let count: u64 = count.parse().map_err(|e| format!("invalid count: {}", e))?;
+        if index == 0 || index > count {
+            return Err(format!("index has to be between 1 and {}", count));
+        }
+        Ok(Shard { index, count })
+    }
+}
+
+impl fmt::Display for Shard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+impl Shard {
+    /// Check if a port of a host belongs to the shard
+    pub fn contains(&self, seed: u64, address: IpAddr, port: u16) -> bool {
+        probe_key(seed, address, port) % self.count == self.index - 1
+    }
+
+    /// Limit targets to the ports of the shard, dropping targets without any
+    ///
+    /// Targets keep sharing their port list, other ports are skipped while scanning.
+    pub fn select(&self, targets: Vec<Target>, seed: u64) -> Vec<Target> {
+        targets
+            .into_iter()
+            .filter_map(|mut target| {
+                let address = target.address.ip();
+                target
+                    .ports
+                    .iter()
+                    .any(|port| self.contains(seed, address, port.number))
+                    .then(|| {
+                        target.shard = Some((*self, seed));
+                        target
+                    })
+            })
+            .collect()
+    }
+}
+
+/// Mix the bits of a value, the finalizer of splitmix64
+fn mix(mut x: u64) -> u64 {
+    x ^= x >> 30;
+    x = x.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x ^= x >> 27;
+    x = x.wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Pseudo-random key of a host, the same on every machine & release
+pub fn host_key(seed: u64, address: IpAddr) -> u64 {
+    let key = mix(seed);
+    match address {
+        IpAddr::V4(ip) => mix(key ^ u64::from(u32::from(ip))),
+        IpAddr::V6(ip) => {
+            let ip = u128::from(ip);
+            mix(mix(key ^ (ip >> 64) as u64) ^ ip as u64)
+        }
+    }
+}
+
+/// Pseudo-random key of a port of a host, which assigns it to a shard
+pub fn probe_key(seed: u64, address: IpAddr, port: u16) -> u64 {
+    mix(host_key(seed, address) ^ u64::from(port))
+}
+
+/// Rounds of the Feistel network of a permutation
+const ROUNDS: u64 = 4;
+
+/// Stable pseudo-random order of the indexes `0..len`, chosen by `key`
+///
+/// Each position is computed on its own, so no list of indexes is allocated:
+/// a Feistel network permutes the smallest even power of 2 covering `len`,
+/// results outside `len` are permuted again until they fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Permutation {
+    len: u64,
+    half_bits: u32,
+    key: u64,
+}
+
+impl Permutation {
+    /// Permutation of `0..len`
+    pub fn new(len: usize, key: u64) -> Permutation {
+        let bits = u64::BITS - (len as u64).saturating_sub(1).leading_zeros();
+        Permutation {
+            len: len as u64,
+            half_bits: bits.div_ceil(2).max(1),
+            key,
+        }
+    }
+
+    /// Index at `position` of the order
+    pub fn get(&self, position: usize) -> usize {
+        assert!((position as u64) < self.len, "Position out of range");
+        let mut index = position as u64;
+        loop {
+            index = self.round_trip(index);
+            if index < self.len {
+                return index as usize;
+            }
+        }
+    }
+
+    /// All indexes in order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len as usize).map(|position| self.get(position))
+    }
+
+    /// Permute a value of the power of 2 domain
+    fn round_trip(&self, value: u64) -> u64 {
+        let mask = (1 << self.half_bits) - 1;
+        let (mut left, mut right) = (value >> self.half_bits, value & mask);
+        for round in 0..ROUNDS {
+            let next = left ^ (mix(right ^ mix(self.key ^ round)) & mask);
+            left = right;
+            right = next;
+        }
+        (left << self.half_bits) | right
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::port::Port;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+
+    /// Check parsing shards & rejecting indexes outside the count
+    #[test]
+    fn parse_shard() {
+        assert_eq!("2/4".parse(), Ok(Shard { index: 2, count: 4 }));
+        assert_eq!("1/1".parse::<Shard>().unwrap().to_string(), "1/1");
+        assert!("0/4".parse::<Shard>().is_err());
+        assert!("5/4".parse::<Shard>().is_err());
+        assert!("1/0".parse::<Shard>().is_err());
+        assert!("4".parse::<Shard>().is_err());
+    }
+
+    /// Check that keys do not change between releases
+    #[test]
+    fn stable_probe_keys() {
+        let ipv4: IpAddr = "192.0.2.1".parse().unwrap();
+        let ipv6: IpAddr = "2001:db8::1".parse().unwrap();
+        assert_eq!(probe_key(0, ipv4, 80), 15067368476873515185);
+        assert_eq!(probe_key(7, ipv6, 443), 16847737667641102483);
+        assert_ne!(probe_key(1, ipv4, 80), probe_key(0, ipv4, 80));
+        assert_ne!(probe_key(0, ipv4, 81), probe_key(0, ipv4, 80));
+    }
+
+    /// Check that permutations contain every index once & depend on the key
+    #[test]
+    fn permutations_are_stable() {
+        for len in [0, 1, 2, 3, 5, 64, 1000, 65535] {
+            let permutation = Permutation::new(len, 42);
+            let mut order: Vec<usize> = permutation.iter().collect();
+            assert_eq!(order, Permutation::new(len, 42).iter().collect::<Vec<_>>());
+            order.sort_unstable();
+            assert_eq!(order, (0..len).collect::<Vec<_>>());
+        }
+        let order = |key| Permutation::new(100, key).iter().collect::<Vec<_>>();
+        assert_ne!(order(1), order(2));
+        assert_ne!(order(1), (0..100).collect::<Vec<_>>());
+    }
+
+    /// Check that the shards of targets are disjoint & cover every port
+    #[test]
+    fn shards_partition_ports() {
+        let ports: Arc<[Port]> = (1..=200)
+            .map(|number| Port {
+                service: "unknown",
+                number,
+            })
+            .collect();
+        let targets = || -> Vec<Target> {
+            ["192.0.2.1", "192.0.2.2", "2001:db8::1"]
+                .iter()
+                .map(|ip| {
+                    let address = SocketAddr::new(ip.parse().unwrap(), 0);
+                    Target::new(ip.to_string(), address, ports.clone())
+                })
+                .collect()
+        };
+
+        let mut probed = Vec::new();
+        for index in 1..=3 {
+            let shard = Shard { index, count: 3 };
+            let selected = shard.select(targets(), 42);
+            assert_eq!(selected, shard.select(targets(), 42));
+            for target in selected {
+                assert!(Arc::ptr_eq(&target.ports, &ports));
+                let owned: Vec<usize> = (0..ports.len()).filter(|&i| target.owns(i)).collect();
+                assert!(owned.len() < ports.len());
+                assert_eq!(target.unscanned(), owned.len());
+                for index in owned {
+                    probed.push((target.address, ports[index].number));
+                }
+            }
+        }
+        probed.sort();
+        let mut all: Vec<_> = targets()
+            .into_iter()
+            .flat_map(|target| {
+                let address = target.address;
+                (1..=200).map(move |number| (address, number))
+            })
+            .collect();
+        all.sort();
+        assert_eq!(probed, all);
+    }
+}
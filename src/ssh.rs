@@ -0,0 +1,359 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::process::Stdio;
+use std::sync::{Arc, Mutex as StdMutex};
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader};
+use tokio::process::{Child, Command};
+use tokio::sync::{oneshot, Mutex};
+use tokio::time::{sleep, timeout, Duration};
+
+use crate::error::Error;
+use crate::port::PortState;
+
+/// Time to wait for the connection to the jump host
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Time to wait for the master to log why it could not open a channel
+const REFUSAL_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// A jump host that probes are tunnelled through, using the OpenSSH client
+///
+/// One master connection is shared by all probes (ControlMaster), every probe
+/// opens a direct-tcpip channel to its destination with `ssh -W`. Clients of the
+/// master only learn that a channel failed, the reason is read from the master's log.
+#[derive(Debug)]
+pub struct SshJump {
+    program: PathBuf,
+    destination: String,
+    port: Option<u16>,
+    control_path: PathBuf,
+    master: Mutex<Option<Child>>,
+    log: Arc<StdMutex<MasterLog>>,
+}
+
+/// State read from the log of the master connection
+#[derive(Debug, Default)]
+struct MasterLog {
+    /// Destination of the last stdio forward, until its channel is logged
+    forwarding: Option<SocketAddr>,
+    /// Destinations of the forwarding channels by channel number
+    channels: HashMap<u32, SocketAddr>,
+    /// Probes waiting for the reason their channel failed
+    waiting: HashMap<SocketAddr, oneshot::Sender<PortState>>,
+    /// Last line that is not debug output
+    last: String,
+}
+
+impl MasterLog {
+    /// Track channels & report failures to waiting probes
+    fn read_line(&mut self, line: &str) {
+        if let Some(destination) = line.strip_prefix("debug1: channel_connect_stdio_fwd: ") {
+            self.forwarding = parse_destination(destination);
+        } else if let Some((channel, message)) = channel_message(line) {
+            if message.starts_with("new ") {
+                if let Some(destination) = self.forwarding.take() {
+                    self.channels.insert(channel, destination);
+                }
+            } else if message.starts_with("free: ") {
+                self.channels.remove(&channel);
+            } else if let Some(reason) = message.strip_prefix("open failed: ") {
+                let waiting = self
+                    .channels
+                    .remove(&channel)
+                    .and_then(|destination| self.waiting.remove(&destination));
+                if let Some(waiting) = waiting {
+                    let _ = waiting.send(failure_state(reason));
+                }
+            }
+        }
+        if !line.starts_with("debug") {
+            self.last = line.to_string();
+        }
+    }
+}
+
+impl SshJump {
+    /// Jump host `[user@]host[:port]`, connected to with `program`
+    pub fn new(program: PathBuf, jump: &str) -> Result<SshJump, String> {
+        let (destination, port) = match jump.rsplit_once(':') {
+            Some((destination, port)) if !destination.contains(':') => {
+                let port = port
+                    .parse()
+                    .map_err(|_| format!("invalid ssh port '{}'", port))?;
+                (destination, Some(port))
+            }
+            _ => (jump, None),
+        };
+        if destination.is_empty() || destination.ends_with('@') {
+            return Err(format!("missing ssh host in '{}'", jump));
+        }
+
+        let control_path =
+            std::env::temp_dir().join(format!("port-scanner-{}.ssh", std::process::id()));
+        Ok(SshJump {
+            program,
+            destination: destination.to_string(),
+            port,
+            control_path,
+            master: Mutex::new(None),
+            log: Arc::default(),
+        })
+    }
+
+    /// Start the master connection and wait until it accepts channels
+    pub async fn connect(&self) -> Result<(), Error> {
+        let mut master = self
+            .command()
+            .args(["-v", "-M", "-N"])
+            .arg(&self.destination)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| self.error(e))?;
+        let stderr = master.stderr.take().expect("Stderr is piped");
+        let reading = tokio::spawn(read_log(stderr, self.log.clone()));
+
+        let mut waited = Duration::ZERO;
+        while waited < CONNECT_TIMEOUT {
+            if let Ok(Some(_)) = master.try_wait() {
+                let _ = reading.await;
+                let last = self.log.lock().expect("Log poisoned").last.clone();
+                return Err(self.error(if last.is_empty() {
+                    "ssh exited".to_string()
+                } else {
+                    last
+                }));
+            }
+            let check = self
+                .command()
+                .args(["-O", "check"])
+                .arg(&self.destination)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .await
+                .map_err(|e| self.error(e))?;
+            if check.success() {
+                *self.master.lock().await = Some(master);
+                return Ok(());
+            }
+            sleep(Duration::from_millis(250)).await;
+            waited += Duration::from_millis(250);
+        }
+        Err(self.error("timed out connecting"))
+    }
+
+    /// Probe `target` through a direct-tcpip channel
+    ///
+    /// The state follows from the channel open result, failures are closed when
+    /// the master logged that the destination refused the connection.
+    pub async fn probe(&self, target: SocketAddr) -> Result<PortState, Error> {
+        let (refusal, failure) = oneshot::channel();
+        self.log
+            .lock()
+            .expect("Log poisoned")
+            .waiting
+            .insert(target, refusal);
+        let state = self.open_channel(target, failure).await;
+        self.log
+            .lock()
+            .expect("Log poisoned")
+            .waiting
+            .remove(&target);
+        state
+    }
+
+    /// Open a channel through the master & wait for its result
+    async fn open_channel(
+        &self,
+        target: SocketAddr,
+        failure: oneshot::Receiver<PortState>,
+    ) -> Result<PortState, Error> {
+        let mut child = self
+            .command()
+            .arg("-vv")
+            .arg("-W")
+            .arg(target.to_string())
+            .arg(&self.destination)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| self.error(e))?;
+
+        let stderr = child.stderr.take().expect("Stderr is piped");
+        let mut lines = BufReader::new(stderr).lines();
+        let mut last = String::new();
+        while let Some(line) = lines.next_line().await.map_err(|e| self.error(e))? {
+            if line.contains("master session id") {
+                return Ok(PortState::Open);
+            }
+            if line.contains("Session open refused by peer") {
+                let state = timeout(REFUSAL_TIMEOUT, failure).await;
+                return Ok(state
+                    .ok()
+                    .and_then(Result::ok)
+                    .unwrap_or(PortState::Filtered));
+            }
+            if !line.starts_with("debug") {
+                last = line;
+            }
+        }
+        Err(self.error(last))
+    }
+
+    /// Base command sharing the master connection
+    fn command(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        command
+            .arg("-S")
+            .arg(&self.control_path)
+            .args(["-o", "BatchMode=yes"]);
+        if let Some(port) = self.port {
+            command.arg("-p").arg(port.to_string());
+        }
+        command
+    }
+
+    /// Error about the jump host
+    fn error(&self, reason: impl ToString) -> Error {
+        Error::Ssh {
+            destination: self.destination.clone(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Drop for SshJump {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.control_path);
+    }
+}
+
+/// Read the log of the master until it exits, so it never blocks on a full pipe
+async fn read_log(stderr: impl AsyncRead + Unpin, log: Arc<StdMutex<MasterLog>>) {
+    let mut lines = BufReader::new(stderr).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        log.lock().expect("Log poisoned").read_line(&line);
+    }
+}
+
+/// Destination `host:port` of a stdio forward, hosts are addresses
+fn parse_destination(destination: &str) -> Option<SocketAddr> {
+    let (host, port) = destination.rsplit_once(':')?;
+    Some(SocketAddr::new(host.parse().ok()?, port.parse().ok()?))
+}
+
+/// Number & message of a log line about a channel, e.g. `channel 3: open failed: ...`
+fn channel_message(line: &str) -> Option<(u32, &str)> {
+    let line = line.strip_prefix("debug1: ").unwrap_or(line);
+    let (channel, message) = line.strip_prefix("channel ")?.split_once(": ")?;
+    Some((channel.parse().ok()?, message))
+}
+
+/// Port state from the reason the jump host gave for not opening a channel
+///
+/// - `Connection refused`: the destination refused, closed
+/// - Other reasons: timeouts & unreachable destinations, filtered
+fn failure_state(reason: &str) -> PortState {
+    if reason.contains("Connection refused") {
+        PortState::Closed
+    } else {
+        PortState::Filtered
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    /// Fake ssh client: the master logs what clients append to `<control path>.log`,
+    /// clients & master report the channel results like OpenSSH does for destination
+    /// ports 1, 2 & 3
+    const FAKE_SSH: &str = r#"#!/bin/sh
+log="$2.log"
+fail() {
+  printf 'debug1: channel_connect_stdio_fwd: 10.0.0.1:%s\ndebug1: channel %s: new stdio-forward [stdio-forward]\nchannel %s: open failed: connect failed: %s\n' "$1" "$1" "$1" "$2" >> "$log"
+  echo "Stdio forwarding request failed: Session open refused by peer" >&2
+  exit 255
+}
+case "$*" in
+  *"-M -N"*) : > "$log"; exec tail -f "$log" >&2 ;;
+  *"-O check"*) exit 0 ;;
+  *":1 "*) echo "debug2: mux_client_request_stdio_fwd: master session id: 2" >&2; exec sleep 30 ;;
+  *":2 "*) fail 2 "Connection refused" ;;
+  *":3 "*) fail 3 "Connection timed out" ;;
+  *) echo "Permission denied (publickey)." >&2; exit 255 ;;
+esac
+"#;
+
+    /// Install the fake ssh client in a temporary directory
+    fn fake_ssh(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("{}-{}", name, std::process::id()));
+        std::fs::write(&path, FAKE_SSH).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    /// Check parsing of jump hosts
+    #[test]
+    fn parse_jump() {
+        let jump = SshJump::new("ssh".into(), "admin@bastion.example.com:2222").unwrap();
+        assert_eq!(jump.destination, "admin@bastion.example.com");
+        assert_eq!(jump.port, Some(2222));
+
+        let jump = SshJump::new("ssh".into(), "bastion").unwrap();
+        assert_eq!((jump.destination.as_str(), jump.port), ("bastion", None));
+
+        assert!(SshJump::new("ssh".into(), "admin@").is_err());
+    }
+
+    /// Check that channel results map to port states
+    #[tokio::test]
+    async fn probe_through_jump() {
+        let jump = SshJump::new(fake_ssh("fake-ssh-probe"), "bastion").unwrap();
+        jump.connect().await.unwrap();
+
+        let probe = |port: u16| jump.probe(SocketAddr::from(([10, 0, 0, 1], port)));
+        assert_eq!(probe(1).await.unwrap(), PortState::Open);
+        assert_eq!(probe(2).await.unwrap(), PortState::Closed);
+        assert_eq!(probe(3).await.unwrap(), PortState::Filtered);
+        assert!(matches!(probe(4).await, Err(Error::Ssh { .. })));
+        std::fs::remove_file(jump.control_path.with_extension("ssh.log")).unwrap();
+    }
+
+    /// Check that failures in the master's log reach the probe of their channel
+    #[test]
+    fn read_master_log() {
+        let mut log = MasterLog::default();
+        let (refusal, mut failure) = oneshot::channel();
+        let target = SocketAddr::from(([10, 0, 0, 1], 22));
+        log.waiting.insert(target, refusal);
+        for line in [
+            "debug1: channel_connect_stdio_fwd: 10.0.0.1:22",
+            "debug1: channel 5: new stdio-forward [stdio-forward] (inactive timeout: 0)",
+            "channel 5: open failed: connect failed: Connection refused",
+            "debug1: channel 5: free: stdio-forward",
+        ] {
+            log.read_line(line);
+        }
+        assert_eq!(failure.try_recv(), Ok(PortState::Closed));
+        assert!(log.channels.is_empty());
+
+        log.read_line("debug1: channel_connect_stdio_fwd: 10.0.0.1:80");
+        log.read_line("debug1: channel 5: new stdio-forward [stdio-forward]");
+        log.read_line("debug1: channel 5: free: stdio-forward, nchannels 2");
+        assert!(log.channels.is_empty());
+        assert_eq!(
+            log.last,
+            "channel 5: open failed: connect failed: Connection refused"
+        );
+    }
+}